@@ -0,0 +1,147 @@
+use crate::solution::Answer;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::{read_to_string, write};
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, DayAnswers>,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_1: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_2: Option<Answer>,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Read(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+    Write(PathBuf, io::Error),
+    UnknownPart(u8),
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Answers, AnswersError> {
+        let string = match read_to_string(path) {
+            Ok(string) => string,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(e) => return Err(AnswersError::Read(path.to_path_buf(), e)),
+        };
+        Answers::parse(&string).map_err(|e| AnswersError::Parse(path.to_path_buf(), e))
+    }
+
+    pub fn parse(string: &str) -> Result<Answers, toml::de::Error> {
+        toml::from_str(string)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        let string = toml::to_string(self).expect("answers are always valid TOML");
+        write(path, string).map_err(|e| AnswersError::Write(path.to_path_buf(), e))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        let answers = self.days.get(&day_key(day))?;
+        match part {
+            1 => answers.part_1.as_ref(),
+            2 => answers.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: Answer) -> Result<(), AnswersError> {
+        let answers = self.days.entry(day_key(day)).or_default();
+        match part {
+            1 => answers.part_1 = Some(answer),
+            2 => answers.part_2 = Some(answer),
+            _ => return Err(AnswersError::UnknownPart(part)),
+        }
+        Ok(())
+    }
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Read(path, e) => write!(f, "Failed reading {}: {e}", path.display()),
+            AnswersError::Parse(path, e) => write!(f, "Failed parsing {}: {e}", path.display()),
+            AnswersError::Write(path, e) => write!(f, "Failed writing {}: {e}", path.display()),
+            AnswersError::UnknownPart(part) => write!(f, "No part {part}, expected 1 or 2"),
+        }
+    }
+}
+
+impl Error for AnswersError {}
+
+fn day_key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs::remove_file;
+
+    #[test]
+    fn can_parse_answers() {
+        let answers = Answers::parse("[day07]\npart_1 = 3749\npart_2 = 11387\n").unwrap();
+        assert_eq!(
+            [answers.get(7, 1), answers.get(7, 2), answers.get(8, 1)],
+            [
+                Some(&Answer::Number(3749)),
+                Some(&Answer::Number(11387)),
+                None
+            ]
+        )
+    }
+
+    #[test]
+    fn can_parse_text_answer() {
+        let answers = Answers::parse("[day17]\npart_1 = \"4,6,3,5,6,3,5,2,1,0\"").unwrap();
+        assert_eq!(
+            answers.get(17, 1),
+            Some(&Answer::Text("4,6,3,5,6,3,5,2,1,0".to_string()))
+        )
+    }
+
+    #[test]
+    fn can_refuse_unknown_part() {
+        assert!(Answers::parse("[day07]\npart_3 = 1").is_err())
+    }
+
+    #[test]
+    fn can_refuse_to_set_unknown_part() {
+        let error = Answers::default().set(7, 3, Answer::Number(1)).unwrap_err();
+        assert_eq!(error.to_string(), "No part 3, expected 1 or 2")
+    }
+
+    #[test]
+    fn can_save_and_load_answers() {
+        let path = env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        let mut answers = Answers::default();
+        answers.set(7, 1, Answer::Number(3749)).unwrap();
+        answers.set(12, 2, Answer::Number(1206)).unwrap();
+        answers.save(&path).unwrap();
+        let saved = read_to_string(&path).unwrap();
+        let loaded = Answers::load(&path);
+        remove_file(&path).unwrap();
+        assert_eq!(saved, "[day07]\npart_1 = 3749\n\n[day12]\npart_2 = 1206\n");
+        assert_eq!(loaded.unwrap(), answers)
+    }
+
+    #[test]
+    fn can_load_missing_file_as_empty() {
+        let answers = Answers::load(Path::new("/no/such/answers.toml")).unwrap();
+        assert_eq!(answers, Answers::default())
+    }
+}
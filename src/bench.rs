@@ -0,0 +1,232 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::{read_to_string, write};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub p95_ms: f64,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Report {
+    days: BTreeMap<String, BTreeMap<String, Stats>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub day: String,
+    pub phase: String,
+    pub baseline_ms: f64,
+    pub current_ms: f64,
+    pub regressed: bool,
+}
+
+#[derive(Debug)]
+pub enum ReportError {
+    Read(PathBuf, io::Error),
+    Parse(PathBuf, serde_json::Error),
+    Write(PathBuf, io::Error),
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted: Vec<f64> = samples
+            .iter()
+            .map(|sample| sample.as_secs_f64() * 1000.0)
+            .collect();
+        sorted.sort_by(f64::total_cmp);
+        Stats {
+            runs: sorted.len(),
+            min_ms: percentile(&sorted, 0.0),
+            median_ms: percentile(&sorted, 0.5),
+            p95_ms: percentile(&sorted, 0.95),
+        }
+    }
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Report, ReportError> {
+        let string = read_to_string(path).map_err(|e| ReportError::Read(path.to_path_buf(), e))?;
+        serde_json::from_str(&string).map_err(|e| ReportError::Parse(path.to_path_buf(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ReportError> {
+        let string = serde_json::to_string_pretty(self).expect("reports are always valid JSON");
+        write(path, string + "\n").map_err(|e| ReportError::Write(path.to_path_buf(), e))
+    }
+
+    pub fn insert(&mut self, day: u8, phase: &str, stats: Stats) {
+        self.days
+            .entry(format!("day{day:02}"))
+            .or_default()
+            .insert(phase.to_string(), stats);
+    }
+
+    pub fn compare(&self, baseline: &Report, threshold: f64) -> Vec<Comparison> {
+        let mut comparisons = vec![];
+        for (day, phases) in &self.days {
+            for (phase, stats) in phases {
+                let Some(before) = baseline.days.get(day).and_then(|phases| phases.get(phase))
+                else {
+                    continue;
+                };
+                comparisons.push(Comparison {
+                    day: day.clone(),
+                    phase: phase.clone(),
+                    baseline_ms: before.median_ms,
+                    current_ms: stats.median_ms,
+                    regressed: stats.median_ms > before.median_ms * (1.0 + threshold),
+                });
+            }
+        }
+        comparisons
+    }
+}
+
+impl Comparison {
+    pub fn change(&self) -> Option<f64> {
+        (self.baseline_ms > 0.0).then(|| (self.current_ms - self.baseline_ms) / self.baseline_ms)
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.3}ms, median {:.3}ms, p95 {:.3}ms",
+            self.min_ms, self.median_ms, self.p95_ms
+        )
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let change = match self.change() {
+            Some(change) => format!("{:+.1}%", change * 100.0),
+            None => "from zero".to_string(),
+        };
+        write!(
+            f,
+            "{} {}: median {:.3}ms -> {:.3}ms ({change}){}",
+            self.day,
+            self.phase,
+            self.baseline_ms,
+            self.current_ms,
+            if self.regressed { ", REGRESSION" } else { "" }
+        )
+    }
+}
+
+impl Display for ReportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportError::Read(path, e) => write!(f, "Failed reading {}: {e}", path.display()),
+            ReportError::Parse(path, e) => write!(f, "Failed parsing {}: {e}", path.display()),
+            ReportError::Write(path, e) => write!(f, "Failed writing {}: {e}", path.display()),
+        }
+    }
+}
+
+impl Error for ReportError {}
+
+fn percentile(sorted: &[f64], fraction: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (fraction * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs::remove_file;
+
+    #[test]
+    fn can_find_stats_of_samples() {
+        let samples: Vec<Duration> = (1..=20).rev().map(Duration::from_millis).collect();
+        assert_eq!(
+            Stats::from_samples(&samples),
+            Stats {
+                runs: 20,
+                min_ms: 1.0,
+                median_ms: 10.0,
+                p95_ms: 19.0
+            }
+        )
+    }
+
+    #[test]
+    fn can_find_stats_of_one_sample() {
+        let stats = Stats::from_samples(&[Duration::from_millis(3)]);
+        assert_eq!([stats.min_ms, stats.median_ms, stats.p95_ms], [3.0; 3])
+    }
+
+    #[test]
+    fn can_flag_regression_over_threshold() {
+        let baseline = report_of(&[("parse", 10.0), ("part_1", 10.0), ("part_2", 10.0)]);
+        let current = report_of(&[("parse", 10.5), ("part_1", 12.0), ("part_3", 50.0)]);
+        let comparisons = current.compare(&baseline, 0.1);
+        let regressed: Vec<(&str, bool)> = comparisons
+            .iter()
+            .map(|comparison| (comparison.phase.as_str(), comparison.regressed))
+            .collect();
+        assert_eq!(regressed, vec![("parse", false), ("part_1", true)])
+    }
+
+    #[test]
+    fn can_print_comparison() {
+        let baseline = report_of(&[("part_2", 10.0)]);
+        let current = report_of(&[("part_2", 12.5)]);
+        assert_eq!(
+            current.compare(&baseline, 0.1)[0].to_string(),
+            "day06 part_2: median 10.000ms -> 12.500ms (+25.0%), REGRESSION"
+        )
+    }
+
+    #[test]
+    fn can_print_comparison_with_zero_baseline() {
+        let baseline = report_of(&[("parse", 0.0)]);
+        let current = report_of(&[("parse", 0.0)]);
+        let comparison = &current.compare(&baseline, 0.1)[0];
+        assert_eq!(comparison.change(), None);
+        assert_eq!(
+            comparison.to_string(),
+            "day06 parse: median 0.000ms -> 0.000ms (from zero)"
+        )
+    }
+
+    #[test]
+    fn can_save_and_load_report() {
+        let path = env::temp_dir().join(format!("aoc-bench-{}.json", std::process::id()));
+        let report = report_of(&[("parse", 1.5)]);
+        report.save(&path).unwrap();
+        let loaded = Report::load(&path);
+        remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), report)
+    }
+
+    fn report_of(medians: &[(&str, f64)]) -> Report {
+        let mut report = Report::default();
+        for (phase, median_ms) in medians {
+            let stats = Stats {
+                runs: 1,
+                min_ms: *median_ms,
+                median_ms: *median_ms,
+                p95_ms: *median_ms,
+            };
+            report.insert(6, phase, stats);
+        }
+        report
+    }
+}
@@ -0,0 +1,7 @@
+use advent_of_code_2024::runner;
+use std::env;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    runner::main(env::args().skip(1))
+}
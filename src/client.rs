@@ -0,0 +1,130 @@
+pub mod fetch;
+pub mod guesses;
+#[cfg(test)]
+pub(crate) mod stub_server;
+pub mod submit;
+
+use crate::config::Config;
+use std::env;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+use ureq::{Agent, AgentBuilder, Request};
+
+pub const YEAR: u16 = 2024;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/mikehawes/advent-of-code-2024";
+
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Status(u16, String),
+    Transport(String),
+    Unrecognised(String),
+    Read(PathBuf, io::Error),
+    Write(PathBuf, io::Error),
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            agent: AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Result<Client, ClientError> {
+        let session = env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| config.session.clone())
+            .ok_or(ClientError::MissingSession)?;
+        let base_url = env::var("AOC_BASE_URL")
+            .ok()
+            .or_else(|| config.base_url.clone())
+            .unwrap_or(DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(&base_url, &session))
+    }
+
+    fn day_url(&self, day: u8, path: &str) -> String {
+        format!("{}/{YEAR}/day/{day}{path}", self.base_url)
+    }
+
+    fn request(&self, method: &str, url: &str) -> Request {
+        self.agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(error: ureq::Error) -> ClientError {
+        match error {
+            ureq::Error::Status(status, response) => {
+                ClientError::Status(status, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(transport) => ClientError::Transport(transport.to_string()),
+        }
+    }
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession => {
+                write!(
+                    f,
+                    "No session token, set AOC_SESSION or session in aoc.toml"
+                )
+            }
+            ClientError::Status(status, body) => {
+                write!(f, "Server responded with status {status}: {}", body.trim())
+            }
+            ClientError::Transport(message) => write!(f, "Request failed: {message}"),
+            ClientError::Unrecognised(text) => write!(f, "Unrecognised response: {text}"),
+            ClientError::Read(path, e) => write!(f, "Failed reading {}: {e}", path.display()),
+            ClientError::Write(path, e) => write!(f, "Failed writing {}: {e}", path.display()),
+        }
+    }
+}
+
+impl Error for ClientError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_build_day_url() {
+        let client = Client::new("http://localhost:8080/", "abc");
+        assert_eq!(
+            client.day_url(7, "/input"),
+            "http://localhost:8080/2024/day/7/input"
+        )
+    }
+
+    #[test]
+    fn can_read_settings_from_config() {
+        let config = Config {
+            session: Some("abc".to_string()),
+            base_url: Some("http://localhost:8080".to_string()),
+            ..Config::default()
+        };
+        let client = Client::from_config(&config).unwrap();
+        assert_eq!(
+            [client.base_url, client.session],
+            ["http://localhost:8080", "abc"]
+        )
+    }
+}
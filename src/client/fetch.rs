@@ -0,0 +1,110 @@
+use crate::client::{Client, ClientError};
+use crate::input::InputDir;
+use std::fs::{create_dir_all, write};
+use std::path::PathBuf;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    AlreadyCached(PathBuf),
+}
+
+impl Client {
+    pub fn fetch_input(&self, day: u8) -> Result<String, ClientError> {
+        let response = self.request("GET", &self.day_url(day, "/input")).call()?;
+        response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string()))
+    }
+}
+
+pub fn fetch_day_input(
+    client: &Client,
+    day: u8,
+    dirs: &[InputDir],
+) -> Result<Fetched, ClientError> {
+    if let Some(path) = dirs
+        .iter()
+        .map(|dir| dir.day_input(day))
+        .find(|path| path.is_file())
+    {
+        return Ok(Fetched::AlreadyCached(path));
+    }
+    let path = dirs[0].day_input(day);
+    let input = client.fetch_input(day)?;
+    if let Some(dir) = path.parent() {
+        create_dir_all(dir).map_err(|e| ClientError::Write(dir.to_path_buf(), e))?;
+    }
+    write(&path, input).map_err(|e| ClientError::Write(path.clone(), e))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::stub_server::StubServer;
+    use std::env;
+    use std::fs::{read_to_string, remove_dir_all};
+
+    #[test]
+    fn can_download_input_with_session() {
+        let server = StubServer::start(vec![(200, "3   4\n4   3\n")]);
+        let client = Client::new(&server.base_url, "abc");
+        assert_eq!(client.fetch_input(1).unwrap(), "3   4\n4   3\n");
+        let request = server.next_request();
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1"));
+        assert!(request.contains("Cookie: session=abc"))
+    }
+
+    #[test]
+    fn can_report_rejected_session() {
+        let server = StubServer::start(vec![(400, "Puzzle inputs differ by user.")]);
+        let client = Client::new(&server.base_url, "expired");
+        assert_eq!(
+            client.fetch_input(1).unwrap_err().to_string(),
+            "Server responded with status 400: Puzzle inputs differ by user."
+        )
+    }
+
+    #[test]
+    fn can_cache_input_in_first_dir() {
+        let dir = test_dir("cache");
+        let server = StubServer::start(vec![(200, "125 17")]);
+        let client = Client::new(&server.base_url, "abc");
+        let fetched = fetch_day_input(&client, 11, &[input_dir(&dir)]);
+        let cached = read_to_string(dir.join("day11/input.txt"));
+        remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            fetched.unwrap(),
+            Fetched::Downloaded(dir.join("day11/input.txt"))
+        );
+        assert_eq!(cached.unwrap(), "125 17")
+    }
+
+    #[test]
+    fn can_refuse_to_download_cached_input() {
+        let dir = test_dir("cached");
+        create_dir_all(dir.join("day11")).unwrap();
+        write(dir.join("day11/input.txt"), "125 17").unwrap();
+        let server = StubServer::start(vec![]);
+        let client = Client::new(&server.base_url, "abc");
+        let fetched = fetch_day_input(&client, 11, &[input_dir(&dir)]);
+        remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            fetched.unwrap(),
+            Fetched::AlreadyCached(dir.join("day11/input.txt"))
+        );
+        assert_eq!(server.received_requests(), 0)
+    }
+
+    fn test_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-fetch-{}-{name}", std::process::id()))
+    }
+
+    fn input_dir(path: &std::path::Path) -> InputDir {
+        InputDir {
+            path: path.to_path_buf(),
+            origin: "test",
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::client::submit::Verdict;
+use crate::client::ClientError;
+use std::fs::{create_dir_all, read_to_string, OpenOptions};
+use std::io;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Guess {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub submitted_at: u64,
+    pub wait_until: u64,
+}
+
+#[derive(Debug)]
+pub struct GuessLog {
+    path: PathBuf,
+    guesses: Vec<Guess>,
+}
+
+impl Guess {
+    fn parse(line: &str) -> Option<Guess> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [day, part, answer, verdict, submitted_at, wait_until] = fields[..] else {
+            return None;
+        };
+        Some(Guess {
+            day: day.parse().ok()?,
+            part: part.parse().ok()?,
+            answer: answer.to_string(),
+            verdict: Verdict::from_key(verdict)?,
+            submitted_at: submitted_at.parse().ok()?,
+            wait_until: wait_until.parse().ok()?,
+        })
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\n",
+            self.day,
+            self.part,
+            self.answer,
+            self.verdict.key(),
+            self.submitted_at,
+            self.wait_until
+        )
+    }
+
+    fn is_for(&self, day: u8, part: u8) -> bool {
+        self.day == day && self.part == part
+    }
+}
+
+impl GuessLog {
+    pub fn open(path: &Path) -> Result<GuessLog, ClientError> {
+        let string = match read_to_string(path) {
+            Ok(string) => string,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(ClientError::Read(path.to_path_buf(), e)),
+        };
+        let mut guesses = vec![];
+        for line in string.lines().filter(|line| !line.is_empty()) {
+            let guess = Guess::parse(line).ok_or_else(|| {
+                let message = format!("unrecognised guess: {line:?}");
+                ClientError::Read(
+                    path.to_path_buf(),
+                    io::Error::new(io::ErrorKind::InvalidData, message),
+                )
+            })?;
+            guesses.push(guess);
+        }
+        Ok(GuessLog {
+            path: path.to_path_buf(),
+            guesses,
+        })
+    }
+
+    pub fn previous(&self, day: u8, part: u8, answer: &str) -> Option<&Guess> {
+        self.guesses
+            .iter()
+            .filter(|guess| guess.verdict.is_judgement())
+            .find(|guess| guess.is_for(day, part) && guess.answer == answer)
+    }
+
+    pub fn solved(&self, day: u8, part: u8) -> Option<&Guess> {
+        self.guesses
+            .iter()
+            .find(|guess| guess.is_for(day, part) && guess.verdict == Verdict::Right)
+    }
+
+    pub fn wait_until(&self, day: u8, part: u8) -> u64 {
+        self.guesses
+            .iter()
+            .filter(|guess| guess.is_for(day, part))
+            .map(|guess| guess.wait_until)
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn record(&mut self, guess: Guess) -> Result<(), ClientError> {
+        let write_error = |e| ClientError::Write(self.path.clone(), e);
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            create_dir_all(dir).map_err(write_error)?;
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(guess.to_line().as_bytes()))
+            .map_err(write_error)?;
+        self.guesses.push(guess);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs::remove_file;
+
+    #[test]
+    fn can_read_back_recorded_guesses() {
+        let path = test_path("read-back");
+        let mut log = GuessLog::open(&path).unwrap();
+        log.record(guess(7, 1, "3749", Verdict::TooLow, 60))
+            .unwrap();
+        log.record(guess(7, 1, "3750", Verdict::Right, 0)).unwrap();
+        let reopened = GuessLog::open(&path);
+        remove_file(&path).unwrap();
+        assert_eq!(reopened.unwrap().guesses, log.guesses)
+    }
+
+    #[test]
+    fn can_find_previous_guess() {
+        let log = log_of(vec![guess(7, 1, "3749", Verdict::TooLow, 60)]);
+        assert_eq!(
+            [
+                log.previous(7, 1, "3749").is_some(),
+                log.previous(7, 2, "3749").is_some()
+            ],
+            [true, false]
+        )
+    }
+
+    #[test]
+    fn can_ignore_guess_made_too_recently() {
+        let log = log_of(vec![guess(7, 1, "3749", Verdict::TooRecent, 30)]);
+        assert_eq!(log.previous(7, 1, "3749"), None)
+    }
+
+    #[test]
+    fn can_find_latest_wait_for_part() {
+        let log = log_of(vec![
+            guess(7, 1, "1", Verdict::Wrong, 60),
+            guess(7, 1, "2", Verdict::Wrong, 300),
+            guess(7, 2, "3", Verdict::Wrong, 600),
+        ]);
+        assert_eq!(log.wait_until(7, 1), 1300)
+    }
+
+    #[test]
+    fn can_refuse_unrecognised_line() {
+        let path = test_path("unrecognised");
+        std::fs::write(&path, "7\t1\t3749\n").unwrap();
+        let result = GuessLog::open(&path);
+        remove_file(&path).unwrap();
+        assert!(result.is_err())
+    }
+
+    fn guess(day: u8, part: u8, answer: &str, verdict: Verdict, wait: u64) -> Guess {
+        Guess {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at: 1000,
+            wait_until: 1000 + wait,
+        }
+    }
+
+    fn log_of(guesses: Vec<Guess>) -> GuessLog {
+        GuessLog {
+            path: PathBuf::new(),
+            guesses,
+        }
+    }
+
+    fn test_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-guesses-{}-{name}.tsv", std::process::id()))
+    }
+}
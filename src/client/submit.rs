@@ -0,0 +1,359 @@
+use crate::client::guesses::{Guess, GuessLog};
+use crate::client::{Client, ClientError};
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    TooRecent,
+    AlreadySolved,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Response {
+    pub verdict: Verdict,
+    pub wait: Option<Duration>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Outcome {
+    Submitted(Response),
+    AlreadyGuessed(Guess),
+    AlreadySolved(Guess),
+    CoolingDown(Duration),
+}
+
+impl Verdict {
+    pub fn key(&self) -> &'static str {
+        match self {
+            Verdict::Right => "right",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::TooRecent => "too-recent",
+            Verdict::AlreadySolved => "already-solved",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Verdict> {
+        [
+            Verdict::Right,
+            Verdict::Wrong,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::TooRecent,
+            Verdict::AlreadySolved,
+        ]
+        .into_iter()
+        .find(|verdict| verdict.key() == key)
+    }
+
+    pub fn is_judgement(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Right | Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Verdict::Right => "right answer",
+            Verdict::Wrong => "wrong answer",
+            Verdict::TooHigh => "wrong answer, too high",
+            Verdict::TooLow => "wrong answer, too low",
+            Verdict::TooRecent => "answered too recently",
+            Verdict::AlreadySolved => "already solved",
+        };
+        write!(f, "{text}")
+    }
+}
+
+impl Response {
+    pub fn parse(body: &str) -> Option<Response> {
+        let text = article_text(body);
+        let verdict = if text.contains("That's the right answer") {
+            Verdict::Right
+        } else if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if text.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::TooRecent
+        } else if text.contains("Did you already complete it?") {
+            Verdict::AlreadySolved
+        } else {
+            return None;
+        };
+        Some(Response {
+            verdict,
+            wait: parse_wait(&text).ok()?,
+        })
+    }
+}
+
+impl Client {
+    pub fn post_answer(&self, day: u8, part: u8, answer: &str) -> Result<Response, ClientError> {
+        let level = part.to_string();
+        let response = self
+            .request("POST", &self.day_url(day, "/answer"))
+            .send_form(&[("level", &level), ("answer", answer)])?;
+        let body = response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string()))?;
+        Response::parse(&body).ok_or_else(|| ClientError::Unrecognised(article_text(&body)))
+    }
+}
+
+pub fn submit_answer(
+    client: &Client,
+    log: &mut GuessLog,
+    [day, part]: [u8; 2],
+    answer: &str,
+    now: u64,
+) -> Result<Outcome, ClientError> {
+    if let Some(guess) = log.solved(day, part) {
+        return Ok(Outcome::AlreadySolved(guess.clone()));
+    }
+    if let Some(guess) = log.previous(day, part, answer) {
+        return Ok(Outcome::AlreadyGuessed(guess.clone()));
+    }
+    let wait_until = log.wait_until(day, part);
+    if wait_until > now {
+        return Ok(Outcome::CoolingDown(Duration::from_secs(wait_until - now)));
+    }
+    let response = client.post_answer(day, part, answer)?;
+    log.record(Guess {
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict: response.verdict,
+        submitted_at: now,
+        wait_until: now.saturating_add(response.wait.map_or(0, |wait| wait.as_secs())),
+    })?;
+    Ok(Outcome::Submitted(response))
+}
+
+fn article_text(body: &str) -> String {
+    let article = match (body.find("<article"), body.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &body[start..end],
+        _ => body,
+    };
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    let spaces = Regex::new(r"\s+").unwrap();
+    let text = tags.replace_all(article, "");
+    spaces.replace_all(text.trim(), " ").to_string()
+}
+
+fn parse_wait(text: &str) -> Result<Option<Duration>, ()> {
+    let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    if let Some(captures) = left.captures(text) {
+        let minutes = captures
+            .get(1)
+            .map_or(Ok(0), |m| parse_seconds(m.as_str(), 60))?;
+        let seconds = parse_seconds(&captures[2], 1)?;
+        let wait = minutes.checked_add(seconds).ok_or(())?;
+        return Ok(Some(Duration::from_secs(wait)));
+    }
+    let minutes = Regex::new(r"(?i)wait (one|\d+) minutes?").unwrap();
+    let Some(captures) = minutes.captures(text) else {
+        return Ok(None);
+    };
+    let minutes = match &captures[1] {
+        "one" => 60,
+        number => parse_seconds(number, 60)?,
+    };
+    Ok(Some(Duration::from_secs(minutes)))
+}
+
+fn parse_seconds(number: &str, unit: u64) -> Result<u64, ()> {
+    let number: u64 = number.parse().map_err(|_| ())?;
+    number.checked_mul(unit).ok_or(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::stub_server::StubServer;
+    use std::env;
+    use std::fs::remove_file;
+    use std::path::PathBuf;
+
+    const RIGHT: &str = "<main><article><p>That's the right answer! You are one gold star closer \
+        to finding the Chief Historian. <a href=\"/2024/day/7#part2\">[Continue to Part Two]</a>\
+        </p></article></main>";
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too \
+        high. If you're stuck, make sure you're using the full input data. Please wait one \
+        minute before trying again. <a href=\"/2024/day/7\">[Return to Day 7]</a></p></article>\
+        </main>";
+    const TOO_RECENT: &str = "<main><article><p>You gave an answer too recently; you have to \
+        wait after submitting an answer before trying again.  You have 1m 5s left to wait. \
+        <a href=\"/2024/day/7\">[Return to Day 7]</a></p></article></main>";
+
+    #[test]
+    fn can_parse_right_answer() {
+        assert_eq!(
+            Response::parse(RIGHT),
+            Some(Response {
+                verdict: Verdict::Right,
+                wait: None
+            })
+        )
+    }
+
+    #[test]
+    fn can_parse_too_high_with_wait() {
+        assert_eq!(
+            Response::parse(TOO_HIGH),
+            Some(Response {
+                verdict: Verdict::TooHigh,
+                wait: Some(Duration::from_secs(60))
+            })
+        )
+    }
+
+    #[test]
+    fn can_parse_too_low() {
+        let body = "<article><p>That's not the right answer; your answer is too low.  \
+            Please wait 5 minutes before trying again.</p></article>";
+        assert_eq!(
+            Response::parse(body),
+            Some(Response {
+                verdict: Verdict::TooLow,
+                wait: Some(Duration::from_secs(300))
+            })
+        )
+    }
+
+    #[test]
+    fn can_parse_wrong_answer() {
+        let body = "<article><p>That's not the right answer.  If you're stuck, make sure \
+            you're using the full input data.</p></article>";
+        assert_eq!(
+            Response::parse(body).map(|response| response.verdict),
+            Some(Verdict::Wrong)
+        )
+    }
+
+    #[test]
+    fn can_parse_wait_left() {
+        assert_eq!(
+            Response::parse(TOO_RECENT),
+            Some(Response {
+                verdict: Verdict::TooRecent,
+                wait: Some(Duration::from_secs(65))
+            })
+        )
+    }
+
+    #[test]
+    fn can_refuse_oversized_wait() {
+        let body = "<article><p>You gave an answer too recently; you have to wait after \
+            submitting an answer before trying again.  You have 99999999999999999999s left to \
+            wait.</p></article>";
+        assert_eq!(Response::parse(body), None)
+    }
+
+    #[test]
+    fn can_parse_already_solved() {
+        let body = "<article><p>You don't seem to be solving the right level.  Did you \
+            already complete it? <a href=\"/2024/day/7\">[Return to Day 7]</a></p></article>";
+        assert_eq!(
+            Response::parse(body).map(|response| response.verdict),
+            Some(Verdict::AlreadySolved)
+        )
+    }
+
+    #[test]
+    fn can_post_answer_as_form() {
+        let server = StubServer::start(vec![(200, RIGHT)]);
+        let client = Client::new(&server.base_url, "abc");
+        client.post_answer(7, 2, "11387").unwrap();
+        let request = server.next_request();
+        assert!(request.starts_with("POST /2024/day/7/answer HTTP/1.1"));
+        assert!(request.contains("Cookie: session=abc"));
+        assert!(request.ends_with("level=2&answer=11387"))
+    }
+
+    #[test]
+    fn can_record_guess_with_cooldown() {
+        let path = test_path("cooldown");
+        let server = StubServer::start(vec![(200, TOO_HIGH)]);
+        let client = Client::new(&server.base_url, "abc");
+        let mut log = GuessLog::open(&path).unwrap();
+        let submitted = submit_answer(&client, &mut log, [7, 1], "4000", 1000);
+        let waiting = submit_answer(&client, &mut log, [7, 1], "3749", 1030);
+        let _ = remove_file(&path);
+        assert_eq!(
+            submitted.unwrap(),
+            Outcome::Submitted(Response {
+                verdict: Verdict::TooHigh,
+                wait: Some(Duration::from_secs(60))
+            })
+        );
+        assert_eq!(
+            waiting.unwrap(),
+            Outcome::CoolingDown(Duration::from_secs(30))
+        )
+    }
+
+    #[test]
+    fn can_record_guess_with_huge_cooldown() {
+        const HUGE_WAIT: &str = "<article><p>You gave an answer too recently; you have to wait \
+            after submitting an answer before trying again.  You have 18446744073709551615s left \
+            to wait.</p></article>";
+        let path = test_path("huge-cooldown");
+        let server = StubServer::start(vec![(200, HUGE_WAIT)]);
+        let client = Client::new(&server.base_url, "abc");
+        let mut log = GuessLog::open(&path).unwrap();
+        let submitted = submit_answer(&client, &mut log, [7, 1], "4000", 1000);
+        let waiting = submit_answer(&client, &mut log, [7, 1], "3749", 1030);
+        let _ = remove_file(&path);
+        assert!(matches!(submitted, Ok(Outcome::Submitted(_))));
+        assert_eq!(
+            waiting.unwrap(),
+            Outcome::CoolingDown(Duration::from_secs(u64::MAX - 1030))
+        )
+    }
+
+    #[test]
+    fn can_refuse_duplicate_guess() {
+        let path = test_path("duplicate");
+        let server = StubServer::start(vec![(200, TOO_HIGH)]);
+        let client = Client::new(&server.base_url, "abc");
+        let mut log = GuessLog::open(&path).unwrap();
+        submit_answer(&client, &mut log, [7, 1], "4000", 1000).unwrap();
+        let repeated = submit_answer(&client, &mut log, [7, 1], "4000", 2000);
+        let _ = remove_file(&path);
+        assert!(matches!(
+            repeated.unwrap(),
+            Outcome::AlreadyGuessed(Guess {
+                verdict: Verdict::TooHigh,
+                ..
+            })
+        ));
+        assert_eq!(server.received_requests(), 1)
+    }
+
+    #[test]
+    fn can_report_unrecognised_response() {
+        let server = StubServer::start(vec![(200, "<article><p>Huh?</p></article>")]);
+        let client = Client::new(&server.base_url, "abc");
+        assert_eq!(
+            client.post_answer(7, 1, "1").unwrap_err().to_string(),
+            "Unrecognised response: Huh?"
+        )
+    }
+
+    fn test_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-submit-{}-{name}.tsv", std::process::id()))
+    }
+}
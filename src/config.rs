@@ -0,0 +1,136 @@
+use serde::Deserialize;
+use std::env;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::read_to_string;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Default, Eq, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub input_dir: Option<PathBuf>,
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    pub guess_log: Option<PathBuf>,
+    pub answers: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Read(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+}
+
+impl Config {
+    pub fn load() -> Result<Config, ConfigError> {
+        match config_paths().into_iter().find(|path| path.is_file()) {
+            Some(path) => Config::read(&path),
+            None => Ok(Config::default()),
+        }
+    }
+
+    pub fn read(path: &Path) -> Result<Config, ConfigError> {
+        let string = read_to_string(path).map_err(|e| ConfigError::Read(path.to_path_buf(), e))?;
+        let config =
+            Config::parse(&string).map_err(|e| ConfigError::Parse(path.to_path_buf(), e))?;
+        Ok(config.relative_to(path.parent().unwrap_or(Path::new("."))))
+    }
+
+    pub fn parse(string: &str) -> Result<Config, toml::de::Error> {
+        toml::from_str(string)
+    }
+
+    fn relative_to(self, dir: &Path) -> Config {
+        Config {
+            input_dir: self.input_dir.map(|input_dir| dir.join(input_dir)),
+            guess_log: self.guess_log.map(|guess_log| dir.join(guess_log)),
+            answers: self.answers.map(|answers| dir.join(answers)),
+            ..self
+        }
+    }
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Read(path, e) => write!(f, "Failed reading {}: {e}", path.display()),
+            ConfigError::Parse(path, e) => write!(f, "Failed parsing {}: {e}", path.display()),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+pub fn config_paths() -> Vec<PathBuf> {
+    if let Some(path) = env::var_os("AOC_CONFIG") {
+        return vec![PathBuf::from(path)];
+    }
+    let mut paths = vec![PathBuf::from("aoc.toml")];
+    if let Some(dir) = env::var_os("XDG_CONFIG_HOME") {
+        paths.push(PathBuf::from(dir).join("aoc/config.toml"));
+    } else if let Some(dir) = env::var_os("HOME") {
+        paths.push(PathBuf::from(dir).join(".config/aoc/config.toml"));
+    }
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_empty_config() {
+        assert_eq!(Config::parse("").unwrap(), Config::default())
+    }
+
+    #[test]
+    fn can_parse_input_dir() {
+        assert_eq!(
+            Config::parse("input_dir = \"inputs\"").unwrap(),
+            Config {
+                input_dir: Some(PathBuf::from("inputs")),
+                ..Config::default()
+            }
+        )
+    }
+
+    #[test]
+    fn can_parse_session_and_base_url() {
+        let config =
+            Config::parse("session = \"abc\"\nbase_url = \"http://localhost:8080\"").unwrap();
+        assert_eq!(
+            [config.session, config.base_url],
+            [
+                Some("abc".to_string()),
+                Some("http://localhost:8080".to_string())
+            ]
+        )
+    }
+
+    #[test]
+    fn can_refuse_unknown_setting() {
+        assert!(Config::parse("input_directory = \"inputs\"").is_err())
+    }
+
+    #[test]
+    fn can_resolve_input_dir_relative_to_config_file() {
+        let config = Config::parse("input_dir = \"inputs\"").unwrap();
+        assert_eq!(
+            config.relative_to(Path::new("/home/elf/.config/aoc")),
+            Config {
+                input_dir: Some(PathBuf::from("/home/elf/.config/aoc/inputs")),
+                ..Config::default()
+            }
+        )
+    }
+
+    #[test]
+    fn can_keep_absolute_input_dir() {
+        let config = Config::parse("input_dir = \"/srv/aoc\"").unwrap();
+        assert_eq!(
+            config.relative_to(Path::new("/home/elf")).input_dir,
+            Some(PathBuf::from("/srv/aoc"))
+        )
+    }
+}
@@ -1,4 +1,9 @@
+pub mod generator;
+pub mod oracle;
+
 use crate::input::input_to_string;
+use crate::parse::{parse_number, required, ParseError};
+use crate::solution::Solution;
 use std::cmp::Ordering;
 use std::iter::Peekable;
 use std::path::Path;
@@ -12,20 +17,23 @@ pub struct Vectors {
 
 impl Vectors {
     pub fn read_input<P: AsRef<Path>>(path: P) -> io::Result<Vectors> {
-        Ok(Vectors::parse(&input_to_string(path)?))
+        Ok(Vectors::parse(&input_to_string(path)?)?)
     }
 
-    pub fn parse(string: &str) -> Vectors {
+    pub fn parse(string: &str) -> Result<Vectors, ParseError> {
         let mut left: Vec<i32> = vec![];
         let mut right: Vec<i32> = vec![];
         for line in string.lines() {
             let mut parts = line.split_whitespace();
-            left.push(parts.next().unwrap().parse().unwrap());
-            right.push(parts.next().unwrap().parse().unwrap());
+            let message = "expected two numbers";
+            let left_part = required(DAY, string, parts.next(), line, message)?;
+            let right_part = required(DAY, string, parts.next(), line, message)?;
+            left.push(parse_number(DAY, string, left_part)?);
+            right.push(parse_number(DAY, string, right_part)?);
         }
         left.sort();
         right.sort();
-        Vectors { left, right }
+        Ok(Vectors { left, right })
     }
 
     pub fn total_distance(&self) -> i32 {
@@ -75,6 +83,27 @@ fn scan_frequency(left: i32, right_iter: &mut Peekable<slice::Iter<i32>>) -> i32
     frequency
 }
 
+const DAY: u8 = 1;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = DAY;
+    type Input = Vectors;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vectors, ParseError> {
+        Vectors::parse(input)
+    }
+    fn part_1(input: &Vectors) -> i32 {
+        input.total_distance()
+    }
+    fn part_2(input: &Vectors) -> i32 {
+        input.similarity()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,7 +124,7 @@ mod tests {
 
     #[test]
     fn can_find_similarity_when_last_right_is_in_left() -> io::Result<()> {
-        assert_eq!(Vectors::parse("1   2\n2   2").similarity(), 4);
+        assert_eq!(Vectors::parse("1   2\n2   2")?.similarity(), 4);
         Ok(())
     }
 
@@ -103,10 +132,10 @@ mod tests {
     fn can_parse_line() {
         assert_eq!(
             Vectors::parse("1   2"),
-            Vectors {
+            Ok(Vectors {
                 left: vec![1],
                 right: vec![2]
-            }
+            })
         )
     }
 
@@ -114,10 +143,26 @@ mod tests {
     fn can_parse_lines() {
         assert_eq!(
             Vectors::parse("1   2\n3   4"),
-            Vectors {
+            Ok(Vectors {
                 left: vec![1, 3],
                 right: vec![2, 4]
-            }
+            })
         )
     }
+
+    #[test]
+    fn can_refuse_invalid_number() {
+        let error = Vectors::parse("1   2\n3   x4").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 1, line 2, column 5: expected a number, found \"x4\""
+        )
+    }
+
+    #[test]
+    fn can_refuse_missing_number() {
+        let error = Vectors::parse("1   2\n3").unwrap_err();
+        assert_eq!([error.line, error.column], [2, 1]);
+        assert_eq!(error.message, "expected two numbers")
+    }
 }
@@ -0,0 +1,27 @@
+use crate::generate::{Generated, Rng};
+use std::fmt::Write;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut left: Vec<usize> = (0..size).map(|_| rng.range(10000..=99999)).collect();
+    let mut right: Vec<usize> = (0..size)
+        .map(|_| {
+            if rng.chance(30) {
+                rng.pick(&left)
+            } else {
+                rng.range(10000..=99999)
+            }
+        })
+        .collect();
+    let mut input = String::new();
+    for (l, r) in left.iter().zip(&right) {
+        let _ = writeln!(input, "{l}   {r}");
+    }
+    let similarity: usize = left
+        .iter()
+        .map(|l| l * right.iter().filter(|r| *r == l).count())
+        .sum();
+    left.sort();
+    right.sort();
+    let distance: usize = left.iter().zip(&right).map(|(l, r)| l.abs_diff(*r)).sum();
+    Generated::known(input, Some(distance.into()), Some(similarity.into()))
+}
@@ -0,0 +1,21 @@
+use crate::day01::Vectors;
+use crate::solution::Answer;
+use std::collections::HashMap;
+
+pub fn scan_similarity(input: &str) -> Option<Answer> {
+    Some(Vectors::parse(input).ok()?.similarity().into())
+}
+
+pub fn count_similarity(input: &str) -> Option<Answer> {
+    let vectors = Vectors::parse(input).ok()?;
+    let mut counts: HashMap<i32, i32> = HashMap::new();
+    for right in &vectors.right {
+        *counts.entry(*right).or_insert(0) += 1;
+    }
+    let similarity: i32 = vectors
+        .left
+        .iter()
+        .map(|left| left * counts.get(left).unwrap_or(&0))
+        .sum();
+    Some(similarity.into())
+}
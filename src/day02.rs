@@ -1,4 +1,8 @@
+pub mod generator;
+
 use crate::input::input_to_string;
+use crate::parse::{parse_number, ParseError};
+use crate::solution::Solution;
 use std::io;
 use std::path::Path;
 
@@ -9,13 +13,15 @@ pub struct Reports {
 
 impl Reports {
     pub fn read_input<P: AsRef<Path>>(path: P) -> io::Result<Reports> {
-        Ok(Reports::parse(&input_to_string(path)?))
+        Ok(Reports::parse(&input_to_string(path)?)?)
     }
 
-    fn parse(string: &str) -> Reports {
-        Reports {
-            reports: string.lines().map(Report::parse).collect(),
-        }
+    pub fn parse(string: &str) -> Result<Reports, ParseError> {
+        let reports = string
+            .lines()
+            .map(|line| Report::parse(line).map_err(|e| e.within(string, line)))
+            .collect::<Result<_, _>>()?;
+        Ok(Reports { reports })
     }
 
     pub fn count_safe(&self) -> usize {
@@ -40,13 +46,12 @@ struct Report {
 }
 
 impl Report {
-    fn parse(string: &str) -> Report {
-        Report {
-            levels: string
-                .split_whitespace()
-                .map(|level| level.parse().unwrap())
-                .collect(),
-        }
+    fn parse(string: &str) -> Result<Report, ParseError> {
+        let levels = string
+            .split_whitespace()
+            .map(|level| parse_number(DAY, string, level))
+            .collect::<Result<_, _>>()?;
+        Ok(Report { levels })
     }
 
     fn is_safe_with_tolerance(&self) -> bool {
@@ -93,6 +98,27 @@ fn is_diff_safe(diff: i32, found_diff: i32) -> bool {
         && (found_diff == 0 || found_diff.is_positive() == diff.is_positive())
 }
 
+const DAY: u8 = 2;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = DAY;
+    type Input = Reports;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Reports, ParseError> {
+        Reports::parse(input)
+    }
+    fn part_1(input: &Reports) -> usize {
+        input.count_safe()
+    }
+    fn part_2(input: &Reports) -> usize {
+        input.count_safe_with_tolerance()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -110,6 +136,13 @@ mod tests {
         )
     }
 
+    #[test]
+    fn can_refuse_invalid_level() {
+        let error = Reports::parse("1 2 3\n4 ? 6").unwrap_err();
+        assert_eq!([error.line, error.column], [2, 3]);
+        assert_eq!(error.text, "?")
+    }
+
     #[test]
     fn can_count_safe_in_example() -> io::Result<()> {
         assert_eq!(Reports::read_input("day02/example.txt")?.count_safe(), 2);
@@ -177,6 +210,7 @@ mod tests {
 
     fn parse_reports_as_vecs(string: &str) -> Vec<Vec<i32>> {
         Reports::parse(string)
+            .unwrap()
             .reports
             .iter()
             .map(|report| report.levels.clone())
@@ -184,6 +218,6 @@ mod tests {
     }
 
     fn is_safe_with_tolerance(string: &str) -> bool {
-        Report::parse(string).is_safe_with_tolerance()
+        Report::parse(string).unwrap().is_safe_with_tolerance()
     }
 }
@@ -0,0 +1,42 @@
+use crate::generate::{Generated, Rng};
+use std::fmt::Write;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let mut safe = 0;
+    let mut dampened = 0;
+    for _ in 0..size {
+        let mut levels = increasing_levels(rng);
+        match rng.below(3) {
+            0 => safe += 1,
+            1 => {
+                let at = rng.below(levels.len());
+                levels.insert(at, levels[at]);
+                dampened += 1;
+            }
+            _ => {
+                for level in &mut levels[2..] {
+                    *level += 10;
+                }
+                for level in &mut levels[5..] {
+                    *level += 10;
+                }
+            }
+        }
+        if rng.chance(50) {
+            levels.reverse();
+        }
+        let line: Vec<String> = levels.iter().map(usize::to_string).collect();
+        let _ = writeln!(input, "{}", line.join(" "));
+    }
+    Generated::known(input, Some(safe.into()), Some((safe + dampened).into()))
+}
+
+fn increasing_levels(rng: &mut Rng) -> Vec<usize> {
+    let length = rng.range(7..=9);
+    let mut levels = vec![rng.range(1..=20)];
+    for _ in 1..length {
+        levels.push(levels.last().unwrap() + rng.range(1..=3));
+    }
+    levels
+}
@@ -1,18 +1,20 @@
+pub mod generator;
 mod operations;
 
+use crate::parse::{parse_number, ParseError};
+use crate::solution::Solution;
 use operations::{Configure, Context, Multiplication, Operation};
 use regex::{Captures, Regex};
-use std::str::FromStr;
 
 pub struct Multiplications {
     operations: Vec<Multiplication>,
 }
 
 impl Multiplications {
-    pub fn parse(string: &str) -> Multiplications {
-        Multiplications {
-            operations: parse_multiplications(string),
-        }
+    pub fn parse(string: &str) -> Result<Multiplications, ParseError> {
+        Ok(Multiplications {
+            operations: parse_multiplications(string)?,
+        })
     }
     pub fn sum(&self) -> i32 {
         self.operations.iter().map(Multiplication::result).sum()
@@ -24,10 +26,10 @@ pub struct Operations {
 }
 
 impl Operations {
-    pub fn parse(string: &str) -> Operations {
-        Operations {
-            operations: parse_operations(string),
-        }
+    pub fn parse(string: &str) -> Result<Operations, ParseError> {
+        Ok(Operations {
+            operations: parse_operations(string)?,
+        })
     }
     pub fn run(&self) -> i32 {
         let mut context = Context::new();
@@ -36,42 +38,65 @@ impl Operations {
     }
 }
 
-fn parse_multiplications(string: &str) -> Vec<Multiplication> {
+fn parse_multiplications(string: &str) -> Result<Vec<Multiplication>, ParseError> {
     Regex::new(r"mul\(([0-9]+),([0-9]+)\)")
         .unwrap()
         .captures_iter(string)
-        .map(parse_multiplication)
+        .map(|captures| parse_multiplication(string, captures, 1))
         .collect()
 }
 
-fn parse_multiplication(captures: Captures) -> Multiplication {
-    let a = i32::from_str(captures.get(1).unwrap().as_str()).unwrap();
-    let b = i32::from_str(captures.get(2).unwrap().as_str()).unwrap();
-    Multiplication { a, b }
+fn parse_multiplication(
+    string: &str,
+    captures: Captures,
+    first_group: usize,
+) -> Result<Multiplication, ParseError> {
+    let a = parse_number(DAY, string, captures.get(first_group).unwrap().as_str())?;
+    let b = parse_number(DAY, string, captures.get(first_group + 1).unwrap().as_str())?;
+    Ok(Multiplication { a, b })
 }
 
-fn parse_operations(string: &str) -> Vec<Box<dyn Operation>> {
+fn parse_operations(string: &str) -> Result<Vec<Box<dyn Operation>>, ParseError> {
     Regex::new(r"(do\(\))|(don't\(\))|mul\(([0-9]+),([0-9]+)\)")
         .unwrap()
         .captures_iter(string)
-        .map(parse_operation)
+        .map(|captures| parse_operation(string, captures))
         .collect()
 }
 
-fn parse_operation(captures: Captures) -> Box<dyn Operation> {
+fn parse_operation(string: &str, captures: Captures) -> Result<Box<dyn Operation>, ParseError> {
     if captures.get(1).is_some() {
-        return Box::new(Configure {
+        return Ok(Box::new(Configure {
             multiplication_enabled: true,
-        });
+        }));
     }
     if captures.get(2).is_some() {
-        return Box::new(Configure {
+        return Ok(Box::new(Configure {
             multiplication_enabled: false,
-        });
+        }));
+    }
+    Ok(Box::new(parse_multiplication(string, captures, 3)?))
+}
+
+const DAY: u8 = 3;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = DAY;
+    type Input = (Multiplications, Operations);
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<(Multiplications, Operations), ParseError> {
+        Ok((Multiplications::parse(input)?, Operations::parse(input)?))
+    }
+    fn part_1(input: &(Multiplications, Operations)) -> i32 {
+        input.0.sum()
+    }
+    fn part_2(input: &(Multiplications, Operations)) -> i32 {
+        input.1.run()
     }
-    let a = i32::from_str(captures.get(3).unwrap().as_str()).unwrap();
-    let b = i32::from_str(captures.get(4).unwrap().as_str()).unwrap();
-    Box::new(Multiplication { a, b })
 }
 
 #[cfg(test)]
@@ -80,18 +105,27 @@ mod tests {
 
     #[test]
     fn can_sum_one_multiplication() {
-        assert_eq!(Multiplications::parse("mul(2,4)").sum(), 8)
+        assert_eq!(Multiplications::parse("mul(2,4)").unwrap().sum(), 8)
+    }
+
+    #[test]
+    fn can_refuse_number_too_large() {
+        let error = Multiplications::parse("x\nmul(2,99999999999)")
+            .err()
+            .unwrap();
+        assert_eq!([error.line, error.column], [2, 7]);
+        assert_eq!(error.text, "99999999999")
     }
 
     #[test]
     fn can_sum_multiplications_in_example() {
         let example = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-        assert_eq!(Multiplications::parse(example).sum(), 161)
+        assert_eq!(Multiplications::parse(example).unwrap().sum(), 161)
     }
 
     #[test]
     fn can_apply_operations_in_example() {
         let example = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
-        assert_eq!(Operations::parse(example).run(), 48)
+        assert_eq!(Operations::parse(example).unwrap().run(), 48)
     }
 }
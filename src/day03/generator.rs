@@ -0,0 +1,53 @@
+use crate::generate::{Generated, Rng};
+
+const NOISE: &[char] = &[
+    '!', '@', '#', '$', '%', '^', '&', '*', '(', ')', '[', ']', '{', '}', '<', '>', ',', ';', ':',
+    '?', '\'', '/', '+', '-', ' ', '1', '2', '3', '7', '9',
+];
+
+const DECOYS: &[&str] = &[
+    "mul[3,7]",
+    "mul(4*",
+    "mul ( 2 , 4 )",
+    "mul(6,9!",
+    "do_not()",
+    "don't_()",
+    "what()",
+    "from()",
+    "select(",
+];
+
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let mut sum = 0;
+    let mut enabled_sum = 0;
+    let mut enabled = true;
+    for _ in 0..size {
+        for _ in 0..rng.range(0..=8) {
+            input.push(rng.pick(NOISE));
+        }
+        if rng.chance(20) {
+            input.push_str(rng.pick(DECOYS));
+        }
+        match rng.below(6) {
+            0 => {
+                input.push_str("do()");
+                enabled = true;
+            }
+            1 => {
+                input.push_str("don't()");
+                enabled = false;
+            }
+            _ => {
+                let [a, b] = [rng.range(1..=999), rng.range(1..=999)];
+                input.push_str(&format!("mul({a},{b})"));
+                sum += a * b;
+                if enabled {
+                    enabled_sum += a * b;
+                }
+            }
+        }
+    }
+    input.push('\n');
+    Generated::known(input, Some(sum.into()), Some(enabled_sum.into()))
+}
@@ -1,3 +1,29 @@
 mod find_cursor;
+pub mod generator;
 mod lines;
 pub mod word_search;
+
+use crate::day04::word_search::WordSearch;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+const DAY: u8 = 4;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = DAY;
+    type Input = WordSearch;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<WordSearch, ParseError> {
+        WordSearch::parse(input)
+    }
+    fn part_1(input: &WordSearch) -> i32 {
+        input.count_xmas()
+    }
+    fn part_2(input: &WordSearch) -> i32 {
+        input.count_x_mas()
+    }
+}
@@ -0,0 +1,13 @@
+use crate::generate::{Generated, Rng};
+
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.max(4);
+    let mut input = String::with_capacity((side + 1) * side);
+    for _ in 0..side {
+        for _ in 0..side {
+            input.push(rng.pick(&['X', 'M', 'A', 'S']));
+        }
+        input.push('\n');
+    }
+    Generated::unknown(input)
+}
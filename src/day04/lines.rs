@@ -1,80 +1,22 @@
-use std::cmp::min;
+use crate::geometry::Point;
+use crate::grid::{Grid, Line};
 
-pub type Point = (usize, usize);
-pub type Line = Vec<Point>;
 pub type LinesIter = dyn Iterator<Item = Line>;
 
-pub fn generate_lines(width: usize, height: usize) -> Box<LinesIter> {
+pub fn generate_lines<T>(grid: &Grid<T>) -> Box<LinesIter> {
+    let diagonals = grid
+        .diagonals_down_right()
+        .into_iter()
+        .chain(grid.diagonals_up_right())
+        .filter(|line| line.len() > 1);
     Box::new(
-        generate_horizontals(width, height)
-            .chain(generate_verticals(width, height))
-            .chain(generate_diagonals(width, height)),
-    )
-}
-fn generate_horizontals(width: usize, height: usize) -> Box<LinesIter> {
-    let starts_at_left = 0..height;
-    Box::new(starts_at_left.map(move |y| horizontal_from_left(width, y)))
-}
-fn horizontal_from_left(width: usize, y: usize) -> Line {
-    let progress = 0..width;
-    progress.clone().map(move |x| (x, y)).collect()
-}
-fn generate_verticals(width: usize, height: usize) -> Box<LinesIter> {
-    let starts_at_top = 0..width;
-    Box::new(starts_at_top.map(move |x| vertical_from_top(x, height)))
-}
-fn vertical_from_top(x: usize, height: usize) -> Line {
-    let progress = 0..height;
-    progress.map(move |y| (x, y)).collect()
-}
-fn generate_diagonals(width: usize, height: usize) -> Box<LinesIter> {
-    let down_right = generate_diagonals_down_right(width, height);
-    let up_right = generate_diagonals_up_right(width, height);
-    Box::new(down_right.chain(up_right))
-}
-
-fn generate_diagonals_down_right(width: usize, height: usize) -> Box<LinesIter> {
-    let starts_at_side = (1..(height - 1)).rev();
-    let starts_at_top = 0..(width - 1);
-    Box::new(
-        starts_at_side
-            .map(move |y| diagonal_from_side_down(width, height, y))
-            .chain(starts_at_top.map(move |x| diagonal_from_top(width, height, x))),
-    )
-}
-
-fn generate_diagonals_up_right(width: usize, height: usize) -> Box<LinesIter> {
-    let starts_at_side = 1..(height - 1);
-    let starts_at_bottom = 0..(width - 1);
-    Box::new(
-        starts_at_side
-            .map(move |y| diagonal_from_side_up(width, y))
-            .chain(starts_at_bottom.map(move |x| diagonal_from_bottom(width, height, x))),
+        grid.rows()
+            .into_iter()
+            .chain(grid.columns())
+            .chain(diagonals.collect::<Vec<Line>>()),
     )
 }
 
-fn diagonal_from_top(width: usize, height: usize, x: usize) -> Line {
-    let diffs = 0..min(height, width - x);
-    diffs.map(move |diff| (x + diff, diff)).collect()
-}
-
-fn diagonal_from_bottom(width: usize, height: usize, x: usize) -> Line {
-    let diffs = 0..min(height, width - x);
-    diffs
-        .map(move |diff| (x + diff, height - diff - 1))
-        .collect()
-}
-
-fn diagonal_from_side_down(width: usize, height: usize, y: usize) -> Line {
-    let diffs = 0..min(width, height - y);
-    diffs.map(move |diff| (diff, y + diff)).collect()
-}
-
-fn diagonal_from_side_up(width: usize, y: usize) -> Line {
-    let diffs = 0..min(width, y + 1);
-    diffs.map(move |diff| (diff, y - diff)).collect()
-}
-
 pub fn generate_x_lines(width: usize, height: usize) -> Box<LinesIter> {
     Box::new(
         at_x_positions(width, height, generate_right_x)
@@ -89,34 +31,35 @@ fn at_x_positions(
     height: usize,
     generator: fn(x: usize, y: usize) -> Line,
 ) -> Box<LinesIter> {
-    Box::new((0..=(width - 3)).flat_map(move |x| (0..=(height - 3)).map(move |y| generator(x, y))))
+    let [xs, ys] = [width, height].map(|length| 0..length.saturating_sub(2));
+    Box::new(xs.flat_map(move |x| ys.clone().map(move |y| generator(x, y))))
 }
 
 fn generate_right_x(x: usize, y: usize) -> Line {
     (0..3)
-        .map(|n| (n + x, n + y))
-        .chain((0..3).map(|n| (n + x, 2 - n + y)))
+        .map(|n| Point::new(n + x, n + y))
+        .chain((0..3).map(|n| Point::new(n + x, 2 - n + y)))
         .collect()
 }
 
 fn generate_left_x(x: usize, y: usize) -> Line {
     (0..3)
-        .map(|n| (2 - n + x, n + y))
-        .chain((0..3).map(|n| (2 - n + x, 2 - n + y)))
+        .map(|n| Point::new(2 - n + x, n + y))
+        .chain((0..3).map(|n| Point::new(2 - n + x, 2 - n + y)))
         .collect()
 }
 
 fn generate_up_x(x: usize, y: usize) -> Line {
     (0..3)
-        .map(|n| (n + x, 2 - n + y))
-        .chain((0..3).map(|n| (2 - n + x, 2 - n + y)))
+        .map(|n| Point::new(n + x, 2 - n + y))
+        .chain((0..3).map(|n| Point::new(2 - n + x, 2 - n + y)))
         .collect()
 }
 
 fn generate_down_x(x: usize, y: usize) -> Line {
     (0..3)
-        .map(|n| (n + x, n + y))
-        .chain((0..3).map(|n| (2 - n + x, n + y)))
+        .map(|n| Point::new(n + x, n + y))
+        .chain((0..3).map(|n| Point::new(2 - n + x, n + y)))
         .collect()
 }
 
@@ -152,8 +95,15 @@ mod tests {
         assert_snapshot!(print_x_lines(4, 4))
     }
 
+    #[test]
+    fn can_generate_no_x_lines_for_small_grid() {
+        assert_eq!(generate_x_lines(4, 1).count(), 0);
+        assert_eq!(generate_x_lines(2, 5).count(), 0)
+    }
+
     fn print_lines(width: usize, height: usize) -> String {
-        print_lines_iter(generate_lines(width, height), width, height)
+        let grid = Grid::filled(width, height, '.');
+        print_lines_iter(generate_lines(&grid), width, height)
     }
 
     fn print_x_lines(width: usize, height: usize) -> String {
@@ -185,11 +135,11 @@ mod tests {
         height: usize,
         out: &mut W,
     ) -> std::fmt::Result {
-        let point_to_n: HashMap<(usize, usize), usize> =
+        let point_to_n: HashMap<Point, usize> =
             HashMap::from_iter(line.iter().enumerate().map(|(n, point)| (*point, n)));
         for y in 0..height {
             for x in 0..width {
-                if let Some(n) = point_to_n.get(&(x, y)) {
+                if let Some(n) = point_to_n.get(&Point::new(x, y)) {
                     out.write_char(char::from_digit(((n + 1) % 10) as u32, 10).unwrap())?;
                 } else {
                     out.write_char('.')?;
@@ -1,18 +1,22 @@
 use crate::day04::find_cursor::FindCursor;
-use crate::day04::lines::{generate_lines, generate_x_lines, Point};
+use crate::day04::lines::{generate_lines, generate_x_lines};
+use crate::day04::DAY;
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::render::palette::Palette;
+use crate::render::Snapshot;
 use std::collections::HashSet;
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct WordSearch {
-    tiles: Vec<Vec<char>>,
-    width: usize,
+    grid: Grid<char>,
 }
 
 impl WordSearch {
-    pub fn parse(input: &str) -> WordSearch {
-        let tiles: Vec<Vec<char>> = input.lines().map(read_chars).collect();
-        let width = tiles.first().map(Vec::len).unwrap_or(0);
-        WordSearch { tiles, width }
+    pub fn parse(input: &str) -> Result<WordSearch, ParseError> {
+        let grid = Grid::parse(DAY, input)?;
+        Ok(WordSearch { grid })
     }
 
     pub fn count_xmas(&self) -> i32 {
@@ -27,7 +31,7 @@ impl WordSearch {
 
     fn count_xmas_find_points(&self) -> (i32, HashSet<Point>) {
         let mut search = Search::new(self, "XMAS");
-        for line in generate_lines(self.width, self.tiles.len()) {
+        for line in generate_lines(&self.grid) {
             search.check_line(line.iter());
             search.check_line(line.iter().rev());
         }
@@ -36,15 +40,43 @@ impl WordSearch {
 
     fn count_x_mas_find_points(&self) -> (i32, HashSet<Point>) {
         let mut search = Search::new(self, "MASMAS");
-        for line in generate_x_lines(self.width, self.tiles.len()) {
+        for line in generate_x_lines(self.grid.width(), self.grid.height()) {
             search.check_line(line.iter());
         }
         (search.matches, search.relevant_points)
     }
 
     fn char(&self, point: &Point) -> char {
-        let (x, y) = *point;
-        self.tiles[y][x]
+        self.grid[*point]
+    }
+
+    fn highlight(&self, points: &HashSet<Point>) -> Grid<char> {
+        let [width, height] = self.grid.size();
+        Grid::from_fn(width, height, |point| {
+            if points.contains(&point) {
+                self.char(&point)
+            } else {
+                '.'
+            }
+        })
+    }
+}
+
+impl Snapshot for WordSearch {
+    fn snapshot(&self, part: u8) -> Grid<char> {
+        let (_, points) = match part {
+            1 => self.count_xmas_find_points(),
+            _ => self.count_x_mas_find_points(),
+        };
+        self.highlight(&points)
+    }
+
+    fn palette(&self) -> Palette {
+        Palette::new([15, 15, 35])
+            .with("X", [255, 80, 80])
+            .with("M", [255, 200, 60])
+            .with("A", [80, 220, 120])
+            .with("S", [90, 150, 255])
     }
 }
 
@@ -92,57 +124,52 @@ impl Search<'_> {
     }
 }
 
-fn read_chars(line: &str) -> Vec<char> {
-    line.chars().collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::input::input_to_string;
     use insta::assert_snapshot;
-    use std::fmt::Write;
 
     #[test]
     fn can_find_no_xmas() {
-        let word_search = WordSearch::parse("ABCD");
+        let word_search = WordSearch::parse("ABCD").unwrap();
         assert_eq!(word_search.count_xmas(), 0)
     }
 
     #[test]
     fn can_find_xmas_one_line() {
-        let word_search = WordSearch::parse("XMAS");
+        let word_search = WordSearch::parse("XMAS").unwrap();
         assert_eq!(word_search.count_xmas(), 1)
     }
 
     #[test]
     fn can_find_xmas_two_lines() {
-        let word_search = WordSearch::parse("XMAS\nXMAS");
+        let word_search = WordSearch::parse("XMAS\nXMAS").unwrap();
         assert_eq!(word_search.count_xmas(), 2)
     }
 
     #[test]
     fn can_find_xmas_one_of_two_lines() {
-        let word_search = WordSearch::parse("XMAS\nXMEN");
+        let word_search = WordSearch::parse("XMAS\nXMEN").unwrap();
         assert_eq!(word_search.count_xmas(), 1)
     }
 
     #[test]
     fn can_find_xmas_over_lines() {
-        let word_search = WordSearch::parse("X\nM\nA\nS");
+        let word_search = WordSearch::parse("X\nM\nA\nS").unwrap();
         assert_eq!(word_search.count_xmas(), 1)
     }
 
     #[test]
     fn can_find_xmas_after_x() {
-        let word_search = WordSearch::parse("XXMAS");
+        let word_search = WordSearch::parse("XXMAS").unwrap();
         assert_eq!(word_search.count_xmas(), 1)
     }
 
     #[test]
     fn can_find_xmas_in_example() {
         let example = input_to_string("day04/example.txt").unwrap();
-        let word_search = WordSearch::parse(example.as_str());
+        let word_search = WordSearch::parse(example.as_str()).unwrap();
         let (matches, points) = word_search.count_xmas_find_points();
         assert_eq!(matches, 18);
         assert_snapshot!(print_relevant_points(&word_search, &points))
@@ -155,14 +182,15 @@ mod tests {
                    _M__\n\
                    __A_\n\
                    ___S",
-        );
+        )
+        .unwrap();
         assert_eq!(word_search.count_xmas(), 1)
     }
 
     #[test]
     fn can_find_x_mas_in_example() {
         let example = input_to_string("day04/example.txt").unwrap();
-        let word_search = WordSearch::parse(example.as_str());
+        let word_search = WordSearch::parse(example.as_str()).unwrap();
         let (matches, points) = word_search.count_x_mas_find_points();
         assert_eq!(matches, 9);
         assert_snapshot!(print_relevant_points(&word_search, &points))
@@ -176,36 +204,18 @@ mod tests {
             GEH";
         assert_eq!(
             WordSearch::parse(input),
-            WordSearch {
-                tiles: vec![
-                    vec!['A', 'B', 'C'],
-                    vec!['D', 'E', 'F'],
-                    vec!['G', 'E', 'H']
-                ],
-                width: 3
-            }
+            Ok(WordSearch {
+                grid: Grid::new(3, 3, vec!['A', 'B', 'C', 'D', 'E', 'F', 'G', 'E', 'H'])
+            })
         );
     }
+
+    #[test]
+    fn can_refuse_ragged_lines() {
+        let error = WordSearch::parse("XMAS\nXMA\nXMAS").unwrap_err();
+        assert_eq!([error.line, error.column], [2, 1])
+    }
     fn print_relevant_points(word_search: &WordSearch, points: &HashSet<Point>) -> String {
-        let mut str: String = "".to_owned();
-        write_relevant_points(word_search, points, &mut str).unwrap();
-        str
-    }
-    fn write_relevant_points<W: Write>(
-        word_search: &WordSearch,
-        points: &HashSet<Point>,
-        out: &mut W,
-    ) -> std::fmt::Result {
-        for y in 0..word_search.tiles.len() {
-            for x in 0..word_search.width {
-                if points.contains(&(x, y)) {
-                    out.write_char(word_search.char(&(x, y)))?;
-                } else {
-                    out.write_char('.')?;
-                }
-            }
-            out.write_char('\n')?;
-        }
-        Ok(())
+        word_search.highlight(points).to_string()
     }
 }
@@ -1,4 +1,30 @@
+pub mod generator;
 pub mod page_ordering;
 mod page_ordering_rule;
 mod rules_index;
 mod update;
+
+use crate::day05::page_ordering::PageOrdering;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+const DAY: u8 = 5;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = DAY;
+    type Input = PageOrdering;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<PageOrdering, ParseError> {
+        PageOrdering::parse(input)
+    }
+    fn part_1(input: &PageOrdering) -> i32 {
+        input.sum_correct_middle_pages()
+    }
+    fn part_2(input: &PageOrdering) -> i32 {
+        input.sum_corrected_middle_pages()
+    }
+}
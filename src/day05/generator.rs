@@ -0,0 +1,38 @@
+use crate::generate::{Generated, Rng};
+use std::fmt::Write;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut pages: Vec<usize> = (10..=99).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(size.clamp(5, pages.len()));
+    let mut rules = vec![];
+    for (i, before) in pages.iter().enumerate() {
+        for after in &pages[i + 1..] {
+            rules.push(format!("{before}|{after}"));
+        }
+    }
+    rng.shuffle(&mut rules);
+    let mut input = rules.join("\n");
+    input.push_str("\n\n");
+    let mut correct = 0;
+    let mut corrected = 0;
+    for _ in 0..size {
+        let length = rng.range(1..=(pages.len() - 1) / 2) * 2 + 1;
+        let mut order: Vec<usize> = (0..pages.len()).collect();
+        rng.shuffle(&mut order);
+        order.truncate(length);
+        order.sort();
+        let middle = pages[order[length / 2]];
+        if rng.chance(50) {
+            rng.shuffle(&mut order);
+        }
+        if order.is_sorted() {
+            correct += middle;
+        } else {
+            corrected += middle;
+        }
+        let update: Vec<String> = order.iter().map(|i| pages[*i].to_string()).collect();
+        let _ = writeln!(input, "{}", update.join(","));
+    }
+    Generated::known(input, Some(correct.into()), Some(corrected.into()))
+}
@@ -1,20 +1,29 @@
 use crate::day05::rules_index::RulesIndex;
 use crate::day05::update::Update;
+use crate::day05::DAY;
+use crate::parse::ParseError;
+use crate::trace::span;
 
+#[derive(Debug, Eq, PartialEq)]
 pub struct PageOrdering {
     rules_index: RulesIndex,
     updates: Vec<Update>,
 }
 
 impl PageOrdering {
-    pub fn parse(string: &str) -> PageOrdering {
-        let (rules_str, updates_str) = string.split_once("\n\n").unwrap();
-        PageOrdering {
-            rules_index: RulesIndex::parse_rules(rules_str),
-            updates: Update::parse_to_vec(updates_str),
-        }
+    pub fn parse(string: &str) -> Result<PageOrdering, ParseError> {
+        let (rules_str, updates_str) = string.split_once("\n\n").ok_or_else(|| {
+            let end = &string[string.len()..];
+            ParseError::at(DAY, string, end, "expected a blank line after the rules")
+        })?;
+        Ok(PageOrdering {
+            rules_index: RulesIndex::parse_rules(rules_str)?,
+            updates: Update::parse_to_vec(updates_str)
+                .map_err(|e| e.within(string, updates_str))?,
+        })
     }
     pub fn sum_correct_middle_pages(&self) -> i32 {
+        let _check = span("check updates");
         self.updates
             .iter()
             .filter(|update| self.is_correct(update))
@@ -22,12 +31,21 @@ impl PageOrdering {
             .sum()
     }
     pub fn sum_corrected_middle_pages(&self) -> i32 {
+        let _sort = span("sort updates");
         self.updates
             .iter()
             .filter(|update| !self.is_correct(update))
             .map(|update| self.sort(update).middle())
             .sum()
     }
+    pub fn print(&self) -> String {
+        let updates: String = self
+            .updates
+            .iter()
+            .map(|update| format!("{}\n", update.print()))
+            .collect();
+        format!("{}\n\n{updates}", self.rules_index.print())
+    }
     fn is_correct(&self, update: &Update) -> bool {
         self.rules_index.matches(update)
     }
@@ -40,18 +58,44 @@ impl PageOrdering {
 mod tests {
     use super::*;
     use crate::input::input_to_string;
+    use crate::property::{assert_round_trips, Shrink};
 
     #[test]
     fn can_sum_correct_middle_pages_for_example() {
         let example = input_to_string("day05/example.txt").unwrap();
-        let ordering = PageOrdering::parse(example.as_str());
+        let ordering = PageOrdering::parse(example.as_str()).unwrap();
         assert_eq!(ordering.sum_correct_middle_pages(), 143);
     }
 
     #[test]
     fn can_sum_corrected_middle_pages_for_example() {
         let example = input_to_string("day05/example.txt").unwrap();
-        let ordering = PageOrdering::parse(example.as_str());
+        let ordering = PageOrdering::parse(example.as_str()).unwrap();
         assert_eq!(ordering.sum_corrected_middle_pages(), 123);
     }
+
+    #[test]
+    fn can_print_page_ordering() {
+        let ordering = PageOrdering::parse("2|3\n1|2\n\n1,2,3\n3,1\n").unwrap();
+        assert_eq!(ordering.print(), "1|2\n2|3\n\n1,2,3\n3,1\n")
+    }
+
+    #[test]
+    fn can_round_trip_generated_page_orderings() {
+        assert_round_trips(DAY, Shrink::Lines, PageOrdering::parse, PageOrdering::print)
+    }
+
+    #[test]
+    fn can_refuse_invalid_update() {
+        let error = PageOrdering::parse("1|2\n3|4\n\n1,2\n3,x").err().unwrap();
+        assert_eq!([error.line, error.column], [5, 3]);
+        assert_eq!(error.text, "x")
+    }
+
+    #[test]
+    fn can_refuse_missing_updates() {
+        let error = PageOrdering::parse("1|2\n3|4").err().unwrap();
+        assert_eq!([error.line, error.column], [2, 4]);
+        assert_eq!(error.message, "expected a blank line after the rules")
+    }
 }
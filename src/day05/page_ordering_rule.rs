@@ -1,4 +1,5 @@
-use std::str::FromStr;
+use crate::day05::DAY;
+use crate::parse::{parse_number, ParseError};
 
 pub struct PageOrderingRule {
     pub lower_page: i32,
@@ -6,17 +7,22 @@ pub struct PageOrderingRule {
 }
 
 impl PageOrderingRule {
-    pub fn parse_to_vec(string: &str) -> Vec<PageOrderingRule> {
-        string.lines().map(PageOrderingRule::parse).collect()
+    pub fn parse_to_vec(string: &str) -> Result<Vec<PageOrderingRule>, ParseError> {
+        string
+            .lines()
+            .map(|line| PageOrderingRule::parse(line).map_err(|e| e.within(string, line)))
+            .collect()
     }
-    pub fn parse(string: &str) -> PageOrderingRule {
-        let (left, right) = string.split_once('|').unwrap();
-        let lower_page = i32::from_str(left).unwrap();
-        let higher_page = i32::from_str(right).unwrap();
-        PageOrderingRule {
+    pub fn parse(string: &str) -> Result<PageOrderingRule, ParseError> {
+        let (left, right) = string
+            .split_once('|')
+            .ok_or_else(|| ParseError::at(DAY, string, string, "expected a rule like 1|2"))?;
+        let lower_page = parse_number(DAY, string, left)?;
+        let higher_page = parse_number(DAY, string, right)?;
+        Ok(PageOrderingRule {
             lower_page,
             higher_page,
-        }
+        })
     }
 }
 
@@ -32,8 +38,16 @@ mod tests {
         )
     }
 
+    #[test]
+    fn can_refuse_rule_without_separator() {
+        let error = PageOrderingRule::parse_to_vec("1|2\n34").err().unwrap();
+        assert_eq!([error.line, error.column], [2, 1]);
+        assert_eq!(error.text, "34")
+    }
+
     fn parse_to_tuples(string: &str) -> Vec<(i32, i32)> {
         PageOrderingRule::parse_to_vec(string)
+            .unwrap()
             .iter()
             .map(|rule| (rule.lower_page, rule.higher_page))
             .collect()
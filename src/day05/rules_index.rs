@@ -1,19 +1,23 @@
 use crate::day05::page_ordering_rule::PageOrderingRule;
 use crate::day05::update::Update;
+use crate::parse::ParseError;
+use crate::trace::span;
 use std::cmp::Ordering;
 use std::cmp::Ordering::{Equal, Greater, Less};
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 
+#[derive(Debug, Eq, PartialEq)]
 pub struct RulesIndex {
     lower_pages: HashMap<i32, HashSet<i32>>,
 }
 
 impl RulesIndex {
-    pub fn parse_rules(string: &str) -> RulesIndex {
-        Self::from_rules(PageOrderingRule::parse_to_vec(string))
+    pub fn parse_rules(string: &str) -> Result<RulesIndex, ParseError> {
+        Ok(Self::from_rules(PageOrderingRule::parse_to_vec(string)?))
     }
     pub fn from_rules(rules: Vec<PageOrderingRule>) -> RulesIndex {
+        let _index = span("index rules");
         let mut lower_pages = HashMap::new();
         for rule in rules {
             insert_index(&mut lower_pages, rule.higher_page, rule.lower_page);
@@ -42,6 +46,19 @@ impl RulesIndex {
         }
         Equal
     }
+    pub fn print(&self) -> String {
+        let mut rules: Vec<(i32, i32)> = self
+            .lower_pages
+            .iter()
+            .flat_map(|(higher, lowers)| lowers.iter().map(|lower| (*lower, *higher)))
+            .collect();
+        rules.sort();
+        rules
+            .iter()
+            .map(|(lower, higher)| format!("{lower}|{higher}"))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
 }
 
 fn insert_index(map: &mut HashMap<i32, HashSet<i32>>, key: i32, value: i32) {
@@ -59,7 +76,7 @@ mod tests {
 
     #[test]
     fn can_build_index_from_rules() {
-        let index = RulesIndex::parse_rules("1|2\n2|3\n3|4");
+        let index = RulesIndex::parse_rules("1|2\n2|3\n3|4").unwrap();
         assert_eq!(
             index.lower_pages,
             HashMap::from([
@@ -71,14 +88,16 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn can_check_order_matches_rules() {
-        let index = RulesIndex::parse_rules("1|2\n2|3\n3|4");
-        assert_eq!(index.matches(&Update::parse("1,2,3")), true)
+        let index = RulesIndex::parse_rules("1|2\n2|3\n3|4").unwrap();
+        assert_eq!(index.matches(&Update::parse("1,2,3").unwrap()), true)
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn can_check_order_violates_rule() {
-        let index = RulesIndex::parse_rules("1|2");
-        assert_eq!(index.matches(&Update::parse("2,1")), false)
+        let index = RulesIndex::parse_rules("1|2").unwrap();
+        assert_eq!(index.matches(&Update::parse("2,1").unwrap()), false)
     }
 }
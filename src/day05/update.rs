@@ -1,5 +1,6 @@
 use crate::day05::rules_index::RulesIndex;
-use std::str::FromStr;
+use crate::day05::DAY;
+use crate::parse::{parse_number, ParseError};
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct Update {
@@ -7,22 +8,33 @@ pub struct Update {
 }
 
 impl Update {
-    pub fn parse_to_vec(string: &str) -> Vec<Update> {
-        string.lines().map(Update::parse).collect()
+    pub fn parse_to_vec(string: &str) -> Result<Vec<Update>, ParseError> {
+        string
+            .lines()
+            .map(|line| Update::parse(line).map_err(|e| e.within(string, line)))
+            .collect()
     }
 
-    pub fn parse(string: &str) -> Update {
+    pub fn parse(string: &str) -> Result<Update, ParseError> {
         let pages = string
             .split(",")
-            .map(|part| i32::from_str(part).unwrap())
-            .collect();
-        Update { pages }
+            .map(|part| parse_number(DAY, string, part))
+            .collect::<Result<_, _>>()?;
+        Ok(Update { pages })
     }
 
     pub fn pages(&self) -> impl Iterator<Item = &i32> {
         self.pages.iter()
     }
 
+    pub fn print(&self) -> String {
+        self.pages
+            .iter()
+            .map(|page| page.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
     pub fn middle(&self) -> i32 {
         self.pages[self.pages.len() / 2]
     }
@@ -52,19 +64,20 @@ mod tests {
 
     #[test]
     fn can_find_middle_page_when_odd() {
-        let update = Update::parse("1,2,3");
+        let update = Update::parse("1,2,3").unwrap();
         assert_eq!(update.middle(), 2);
     }
 
     #[test]
     fn can_sort_by_rules() {
-        let update = Update::parse("1,2,3");
-        let rules = RulesIndex::parse_rules("1|2\n3|2\n1|3");
-        assert_eq!(update.sort(&rules), Update::parse("1,3,2"));
+        let update = Update::parse("1,2,3").unwrap();
+        let rules = RulesIndex::parse_rules("1|2\n3|2\n1|3").unwrap();
+        assert_eq!(update.sort(&rules), Update::parse("1,3,2").unwrap());
     }
 
     fn parse_to_vecs(string: &str) -> Vec<Vec<i32>> {
         Update::parse_to_vec(string)
+            .unwrap()
             .iter()
             .map(|update| update.pages.clone())
             .collect()
@@ -1,3 +1,33 @@
-mod direction;
+pub mod generator;
 mod guard;
 pub mod lab_room;
+
+use crate::day06::lab_room::LabRoom;
+use crate::observe;
+use crate::parse::ParseError;
+use crate::pool::Pool;
+use crate::solution::Solution;
+
+const DAY: u8 = 6;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = DAY;
+    type Input = LabRoom;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<LabRoom, ParseError> {
+        LabRoom::parse(input)
+    }
+    fn part_1(input: &LabRoom) -> usize {
+        input.count_visited_positions()
+    }
+    fn part_2(input: &LabRoom) -> usize {
+        input.count_positions_to_obstruct()
+    }
+    fn part_2_in_parallel(input: &LabRoom, pool: &Pool) -> usize {
+        input.count_positions_to_obstruct_in_parallel(pool, observe::current())
+    }
+}
@@ -1,18 +0,0 @@
-#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
-pub enum Direction {
-    Up,
-    Left,
-    Right,
-    Down,
-}
-
-impl Direction {
-    pub fn turn_right(&self) -> Direction {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Left => Direction::Up,
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-        }
-    }
-}
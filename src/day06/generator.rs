@@ -0,0 +1,20 @@
+use crate::generate::{Generated, Rng};
+
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.max(3);
+    let guard = rng.below(side * side);
+    let mut input = String::with_capacity((side + 1) * side);
+    for i in 0..side * side {
+        input.push(if i == guard {
+            '^'
+        } else if rng.chance(8) {
+            '#'
+        } else {
+            '.'
+        });
+        if i % side == side - 1 {
+            input.push('\n');
+        }
+    }
+    Generated::unknown(input)
+}
@@ -1,6 +1,5 @@
-use crate::day06::direction::Direction;
-use crate::day06::direction::Direction::{Down, Left, Right, Up};
-use crate::day06::lab_room::{LabRoom, Point};
+use crate::day06::lab_room::LabRoom;
+use crate::geometry::{Direction, Point};
 
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
 pub struct Guard {
@@ -8,27 +7,26 @@ pub struct Guard {
     pub direction: Direction,
 }
 
+pub enum Step {
+    Moved,
+    LeftRoom,
+    Trapped,
+}
+
 impl Guard {
-    pub fn move_forwards(&mut self, room: &LabRoom) -> bool {
+    pub fn move_forwards(&mut self, room: &LabRoom) -> Step {
         let mut direction = self.direction;
         for _ in 0..4 {
-            let next = self.next_position(direction);
-            if !room.is_obstructed(next) {
-                self.position = next;
-                self.direction = direction;
-                return true;
+            match room.next_position(self.position, direction) {
+                None => return Step::LeftRoom,
+                Some(next) if !room.is_obstructed(next) => {
+                    self.position = next;
+                    self.direction = direction;
+                    return Step::Moved;
+                }
+                Some(_) => direction = direction.turn_right(),
             }
-            direction = direction.turn_right();
-        }
-        false
-    }
-    fn next_position(&self, direction: Direction) -> Point {
-        let (x, y) = self.position;
-        match direction {
-            Up => (x, y.wrapping_sub(1)),
-            Left => (x.wrapping_sub(1), y),
-            Right => (x + 1, y),
-            Down => (x, y + 1),
         }
+        Step::Trapped
     }
 }
@@ -1,14 +1,21 @@
-use crate::day06::direction::Direction::Up;
-use crate::day06::guard::Guard;
+use crate::day06::guard::{Guard, Step};
+use crate::day06::DAY;
+use crate::geometry::Direction::Up;
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::observe::{Observer, Silent};
+use crate::parse::ParseError;
+use crate::pool::Pool;
+use crate::visualise::{Frame, Frames, Visualise};
 use std::collections::HashSet;
+use std::iter;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-pub(super) type Point = (usize, usize);
+const OBSTRUCTION_TASK: &str = "Checking obstructions";
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct LabRoom {
-    width: usize,
-    height: usize,
-    obstructions: HashSet<Point>,
+    obstructions: Grid<bool>,
     guard: Guard,
 }
 
@@ -19,53 +26,64 @@ struct PathResult {
 }
 
 impl LabRoom {
-    pub fn parse(string: &str) -> LabRoom {
-        let width = string.lines().next().unwrap().len();
-        let height = string.lines().count();
-        let mut obstructions = HashSet::new();
-        let mut guard = Guard {
-            position: (0, 0),
-            direction: Up,
-        };
-        for (y, line) in string.lines().enumerate() {
-            for (x, tile) in line.chars().enumerate() {
-                if tile == '#' {
-                    obstructions.insert((x, y));
-                } else if tile == '^' {
-                    guard.position = (x, y);
-                }
-            }
-        }
-        LabRoom {
-            width,
-            height,
-            obstructions,
+    pub fn parse(string: &str) -> Result<LabRoom, ParseError> {
+        let tiles = Grid::parse_with(DAY, string, "expected . # or ^", |tile| {
+            ['.', '#', '^'].contains(&tile).then_some(tile)
+        })?;
+        let guard = tiles
+            .iter()
+            .find(|(_, tile)| **tile == '^')
+            .map(|(position, _)| Guard {
+                position,
+                direction: Up,
+            })
+            .ok_or_else(|| ParseError::at(DAY, string, string, "expected a guard marked with ^"))?;
+        Ok(LabRoom {
+            obstructions: tiles.map(|tile| *tile == '#'),
             guard,
-        }
+        })
     }
     pub fn count_visited_positions(&self) -> usize {
         self.check_path().num_positions()
     }
     pub fn count_positions_to_obstruct(&self) -> usize {
-        let mut positions = 0;
+        self.count_positions_to_obstruct_in_parallel(&Pool::new(1), &Silent)
+    }
+    pub fn count_positions_to_obstruct_in_parallel(
+        &self,
+        pool: &Pool,
+        observer: &dyn Observer,
+    ) -> usize {
         let unobstructed = self.check_path();
-        for y in 0..self.height {
-            println!("Checking line {y}");
-            for x in 0..self.width {
-                if self.guard.position == (x, y) {
-                    continue;
-                }
-                if !unobstructed.positions.contains(&(x, y)) {
-                    continue;
-                }
-                if self.obstructions.contains(&(x, y)) {
-                    continue;
-                }
-                let mut copy = self.clone();
-                copy.obstructions.insert((x, y));
-                if copy.check_path().in_loop {
-                    positions += 1;
-                }
+        let lines: Vec<usize> = (0..self.obstructions.height()).collect();
+        let checked = AtomicUsize::new(0);
+        observer.started(OBSTRUCTION_TASK, lines.len());
+        let positions = pool.sum(&lines, |y| {
+            let positions = self.count_positions_to_obstruct_on_line(*y, &unobstructed);
+            let done = checked.fetch_add(1, Ordering::Relaxed) + 1;
+            observer.progressed(OBSTRUCTION_TASK, done, lines.len());
+            positions
+        });
+        observer.finished(OBSTRUCTION_TASK);
+        positions
+    }
+    fn count_positions_to_obstruct_on_line(&self, y: usize, unobstructed: &PathResult) -> usize {
+        let mut positions = 0;
+        for x in 0..self.obstructions.width() {
+            let point = Point::new(x, y);
+            if self.guard.position == point {
+                continue;
+            }
+            if !unobstructed.positions.contains(&point) {
+                continue;
+            }
+            if self.is_obstructed(point) {
+                continue;
+            }
+            let mut copy = self.clone();
+            copy.obstructions[point] = true;
+            if copy.check_path().in_loop {
+                positions += 1;
             }
         }
         positions
@@ -77,11 +95,10 @@ impl LabRoom {
         positions.insert(guard.position);
         states.insert(guard.clone());
         loop {
-            if !guard.move_forwards(self) {
-                return PathResult::in_loop_after_visiting(positions);
-            }
-            if !self.is_in_room(guard.position) {
-                return PathResult::left_after_visiting(positions);
+            match guard.move_forwards(self) {
+                Step::Moved => {}
+                Step::LeftRoom => return PathResult::left_after_visiting(positions),
+                Step::Trapped => return PathResult::in_loop_after_visiting(positions),
             }
             if states.contains(&guard) {
                 return PathResult::in_loop_after_visiting(positions);
@@ -90,12 +107,45 @@ impl LabRoom {
             states.insert(guard.clone());
         }
     }
-    fn is_in_room(&self, position: Point) -> bool {
-        let (x, y) = position;
-        x < self.width && y < self.height
+    pub(super) fn next_position(&self, position: Point, direction: Direction) -> Option<Point> {
+        self.obstructions.step(position, direction)
     }
     pub(super) fn is_obstructed(&self, position: Point) -> bool {
-        self.obstructions.contains(&position)
+        self.obstructions[position]
+    }
+    fn render(&self, visited: &HashSet<Point>, guard: &Guard) -> Grid<char> {
+        let [width, height] = self.obstructions.size();
+        Grid::from_fn(width, height, |point| {
+            if point == guard.position {
+                guard.direction.arrow()
+            } else if self.obstructions[point] {
+                '#'
+            } else if visited.contains(&point) {
+                'X'
+            } else {
+                '.'
+            }
+        })
+    }
+}
+
+impl Visualise for LabRoom {
+    fn frames(&self) -> Frames<'_> {
+        let mut guard = Some(self.guard.clone());
+        let mut visited = HashSet::new();
+        let mut states = HashSet::new();
+        let mut step = 0;
+        Box::new(iter::from_fn(move || {
+            let current = guard.take()?;
+            visited.insert(current.position);
+            let frame = Frame::new(format!("Step {step}"), self.render(&visited, &current));
+            step += 1;
+            let mut next = current.clone();
+            if states.insert(current) && matches!(next.move_forwards(self), Step::Moved) {
+                guard = Some(next);
+            }
+            Some(frame)
+        }))
     }
 }
 
@@ -120,43 +170,68 @@ impl PathResult {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::day06::direction::Direction::Up;
     use crate::input::input_to_string;
+    use crate::observe::tests::Recorder;
 
     #[test]
     fn can_count_visited_positions_in_example() {
         let string = input_to_string("day06/example.txt").unwrap();
-        let room = LabRoom::parse(string.as_str());
+        let room = LabRoom::parse(string.as_str()).unwrap();
         assert_eq!(room.count_visited_positions(), 41)
     }
 
     #[test]
     fn can_count_positions_to_obstruct_in_example() {
         let string = input_to_string("day06/example.txt").unwrap();
-        let room = LabRoom::parse(string.as_str());
+        let room = LabRoom::parse(string.as_str()).unwrap();
         assert_eq!(room.count_positions_to_obstruct(), 6)
     }
 
+    #[test]
+    fn can_count_positions_to_obstruct_in_parallel() {
+        let string = input_to_string("day06/example.txt").unwrap();
+        let room = LabRoom::parse(string.as_str()).unwrap();
+        let recorder = Recorder::default();
+        let positions = room.count_positions_to_obstruct_in_parallel(&Pool::new(4), &recorder);
+        assert_eq!(positions, 6);
+        let events = recorder.events();
+        assert_eq!(events.len(), 12);
+        assert_eq!(events[0], "Checking obstructions started 10");
+        assert!(events.contains(&"Checking obstructions 10/10".to_string()));
+        assert_eq!(events[11], "Checking obstructions finished")
+    }
+
     #[test]
     fn can_parse_room() {
         let string = "\
                ..#\n\
                ..^";
-        let room = LabRoom::parse(string);
+        let room = LabRoom::parse(string).unwrap();
         assert_eq!(
             room,
             LabRoom {
-                width: 3,
-                height: 2,
-                obstructions: HashSet::from([(2, 0)]),
+                obstructions: Grid::new(3, 2, vec![false, false, true, false, false, false]),
                 guard: Guard {
-                    position: (2, 1),
+                    position: Point::new(2, 1),
                     direction: Up
                 }
             }
         )
     }
 
+    #[test]
+    fn can_refuse_unknown_tile() {
+        let error = LabRoom::parse("..#\n.x^").unwrap_err();
+        assert_eq!([error.line, error.column], [2, 2]);
+        assert_eq!(error.text, "x")
+    }
+
+    #[test]
+    fn can_refuse_room_without_guard() {
+        let error = LabRoom::parse("..#\n...").unwrap_err();
+        assert_eq!(error.message, "expected a guard marked with ^")
+    }
+
     #[test]
     fn can_find_moved_forwards_once() {
         let string = "\
@@ -210,8 +285,22 @@ mod tests {
         assert_eq!(check_path_positions_and_is_loop(string), (4, true))
     }
 
+    #[test]
+    fn can_visualise_guard_walk() {
+        let string = "\
+               ..#\n\
+               ...\n\
+               ..^";
+        let room = LabRoom::parse(string).unwrap();
+        let frames: Vec<String> = room.frames().map(|frame| frame.to_string()).collect();
+        assert_eq!(
+            frames,
+            vec!["Step 0\n..#\n...\n..^\n", "Step 1\n..#\n..^\n..X\n"]
+        )
+    }
+
     fn check_path_positions_and_is_loop(string: &str) -> (usize, bool) {
-        let result = LabRoom::parse(string).check_path();
+        let result = LabRoom::parse(string).unwrap().check_path();
         (result.positions.len(), result.in_loop)
     }
 }
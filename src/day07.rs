@@ -1,2 +1,37 @@
 mod equation;
 pub mod equations;
+pub mod generator;
+
+pub use equation::Equation;
+
+use crate::day07::equations::Equations;
+use crate::parse::ParseError;
+use crate::pool::Pool;
+use crate::solution::Solution;
+
+const DAY: u8 = 7;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = DAY;
+    type Input = Equations;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Equations, ParseError> {
+        Equations::parse(input)
+    }
+    fn part_1(input: &Equations) -> usize {
+        input.sum_possible_answers()
+    }
+    fn part_2(input: &Equations) -> usize {
+        input.sum_possible_answers_with_concat()
+    }
+    fn part_1_in_parallel(input: &Equations, pool: &Pool) -> usize {
+        input.sum_possible_answers_in_parallel(pool)
+    }
+    fn part_2_in_parallel(input: &Equations, pool: &Pool) -> usize {
+        input.sum_possible_answers_with_concat_in_parallel(pool)
+    }
+}
@@ -1,4 +1,6 @@
 use crate::day07::equation::Operator::{Add, Concatenate, Multiply};
+use crate::day07::DAY;
+use crate::parse::{parse_number, ParseError};
 use std::str::FromStr;
 
 #[derive(Debug, Eq, PartialEq)]
@@ -31,21 +33,28 @@ fn concat(left: usize, right: usize) -> usize {
 }
 
 impl Equation {
-    pub fn parse_to_vec(string: &str) -> Vec<Equation> {
-        string.lines().map(Equation::parse).collect()
+    pub fn parse_to_vec(string: &str) -> Result<Vec<Equation>, ParseError> {
+        string
+            .lines()
+            .map(|line| Equation::parse(line).map_err(|e| e.within(string, line)))
+            .collect()
     }
-    fn parse(string: &str) -> Equation {
-        let parts: Vec<&str> = string.split_whitespace().collect();
-        let numbers = parts
-            .iter()
-            .skip(1)
-            .flat_map(|str| usize::from_str(str))
-            .collect();
-        let equals = parts
-            .first()
-            .map(|str| usize::from_str(str.trim_end_matches(":")).unwrap())
-            .unwrap();
-        Equation { numbers, equals }
+    pub fn parse(string: &str) -> Result<Equation, ParseError> {
+        let (equals, numbers) = string
+            .split_once(':')
+            .ok_or_else(|| ParseError::at(DAY, string, string, "expected an answer before :"))?;
+        let numbers: Vec<usize> = numbers
+            .split_whitespace()
+            .map(|number| parse_number(DAY, string, number))
+            .collect::<Result<_, _>>()?;
+        if numbers.is_empty() {
+            let end = &string[string.len()..];
+            return Err(ParseError::at(DAY, string, end, "expected numbers after :"));
+        }
+        Ok(Equation {
+            numbers,
+            equals: parse_number(DAY, string, equals)?,
+        })
     }
     pub fn is_possible_add_multiply(&self) -> bool {
         self.possible_operator_combinations(vec![Add, Multiply])
@@ -108,7 +117,7 @@ mod tests {
     #[test]
     fn can_parse_equations() {
         let string = "4: 2 2\n5: 2 1";
-        let equations = Equation::parse_to_vec(string);
+        let equations = Equation::parse_to_vec(string).unwrap();
         assert_eq!(
             equations,
             vec! {
@@ -124,15 +133,28 @@ mod tests {
         )
     }
 
+    #[test]
+    fn can_refuse_invalid_number() {
+        let error = Equation::parse_to_vec("4: 2 2\n5: 2 y").unwrap_err();
+        assert_eq!([error.line, error.column], [2, 6]);
+        assert_eq!(error.text, "y")
+    }
+
+    #[test]
+    fn can_refuse_missing_numbers() {
+        let error = Equation::parse("4:").unwrap_err();
+        assert_eq!([error.line, error.column], [1, 3])
+    }
+
     #[test]
     fn can_find_all_combinations_for_one_operator() {
-        let equation = Equation::parse("4: 2 2");
+        let equation = Equation::parse("4: 2 2").unwrap();
         assert_eq!(print_all_combinations(&equation), vec!["+", "*"]);
     }
 
     #[test]
     fn can_find_all_combinations_for_two_operators() {
-        let equation = Equation::parse("9: 3 3 3");
+        let equation = Equation::parse("9: 3 3 3").unwrap();
         assert_eq!(
             print_all_combinations(&equation),
             vec!["++", "*+", "+*", "**"]
@@ -141,7 +163,7 @@ mod tests {
 
     #[test]
     fn can_find_all_combinations_for_three_operators() {
-        let equation = Equation::parse("8: 2 2 2 2");
+        let equation = Equation::parse("8: 2 2 2 2").unwrap();
         assert_eq!(
             print_all_combinations(&equation),
             vec!["+++", "*++", "+*+", "**+", "++*", "*+*", "+**", "***"]
@@ -150,13 +172,13 @@ mod tests {
 
     #[test]
     fn can_find_possible_combinations_for_three_operators() {
-        let equation = Equation::parse("8: 2 2 2 2");
+        let equation = Equation::parse("8: 2 2 2 2").unwrap();
         assert_eq!(print_possible_combinations(&equation), vec!["+++", "*++"]);
     }
 
     #[test]
     fn can_find_all_combinations_for_two_operators_with_concatenate() {
-        let equation = Equation::parse("9: 3 3 3");
+        let equation = Equation::parse("9: 3 3 3").unwrap();
         assert_eq!(
             print_all_combinations_with_concatenate(&equation),
             vec!["++", "*+", "|+", "+*", "**", "|*", "+|", "*|", "||"]
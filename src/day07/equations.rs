@@ -1,28 +1,40 @@
 use crate::day07::equation::Equation;
+use crate::parse::ParseError;
+use crate::pool::Pool;
 
 pub struct Equations {
     equations: Vec<Equation>,
 }
 
 impl Equations {
-    pub fn parse(string: &str) -> Equations {
-        Equations {
-            equations: Equation::parse_to_vec(string),
-        }
+    pub fn parse(string: &str) -> Result<Equations, ParseError> {
+        Ok(Equations {
+            equations: Equation::parse_to_vec(string)?,
+        })
     }
     pub fn sum_possible_answers(&self) -> usize {
-        self.equations
-            .iter()
-            .filter(|e| e.is_possible_add_multiply())
-            .map(|e| e.answer())
-            .sum()
+        self.sum_possible_answers_in_parallel(&Pool::new(1))
     }
     pub fn sum_possible_answers_with_concat(&self) -> usize {
-        self.equations
-            .iter()
-            .filter(|e| e.is_possible_add_multiply_concatenate())
-            .map(|e| e.answer())
-            .sum()
+        self.sum_possible_answers_with_concat_in_parallel(&Pool::new(1))
+    }
+    pub fn sum_possible_answers_in_parallel(&self, pool: &Pool) -> usize {
+        pool.sum(&self.equations, |e| {
+            if e.is_possible_add_multiply() {
+                e.answer()
+            } else {
+                0
+            }
+        })
+    }
+    pub fn sum_possible_answers_with_concat_in_parallel(&self, pool: &Pool) -> usize {
+        pool.sum(&self.equations, |e| {
+            if e.is_possible_add_multiply_concatenate() {
+                e.answer()
+            } else {
+                0
+            }
+        })
     }
 }
 
@@ -30,18 +42,37 @@ impl Equations {
 mod tests {
     use crate::day07::equations::Equations;
     use crate::input::input_to_string;
+    use crate::pool::Pool;
 
     #[test]
     fn can_sum_example_possible_answers() {
         let input = input_to_string("day07/example.txt").unwrap();
-        let sum = Equations::parse(input.as_str()).sum_possible_answers();
+        let sum = Equations::parse(input.as_str())
+            .unwrap()
+            .sum_possible_answers();
         assert_eq!(sum, 3749)
     }
 
     #[test]
     fn can_sum_example_possible_answers_with_concat() {
         let input = input_to_string("day07/example.txt").unwrap();
-        let sum = Equations::parse(input.as_str()).sum_possible_answers_with_concat();
+        let sum = Equations::parse(input.as_str())
+            .unwrap()
+            .sum_possible_answers_with_concat();
         assert_eq!(sum, 11387)
     }
+
+    #[test]
+    fn can_sum_example_possible_answers_in_parallel() {
+        let input = input_to_string("day07/example.txt").unwrap();
+        let equations = Equations::parse(input.as_str()).unwrap();
+        let pool = Pool::new(4);
+        assert_eq!(
+            [
+                equations.sum_possible_answers_in_parallel(&pool),
+                equations.sum_possible_answers_with_concat_in_parallel(&pool)
+            ],
+            [3749, 11387]
+        )
+    }
 }
@@ -0,0 +1,21 @@
+use crate::generate::{Generated, Rng};
+use std::fmt::Write;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let mut sum = 0;
+    for _ in 0..size {
+        let numbers: Vec<usize> = (0..rng.range(2..=5)).map(|_| rng.range(1..=99)).collect();
+        let equals = numbers[1..]
+            .iter()
+            .fold(numbers[0], |total, number| match rng.below(3) {
+                0 => total + number,
+                1 => total * number,
+                _ => total * 10usize.pow(number.ilog10() + 1) + number,
+            });
+        sum += equals;
+        let numbers: Vec<String> = numbers.iter().map(usize::to_string).collect();
+        let _ = writeln!(input, "{equals}: {}", numbers.join(" "));
+    }
+    Generated::known(input, None, Some(sum.into()))
+}
@@ -1 +1,27 @@
 pub mod antenna_map;
+pub mod generator;
+
+use crate::day08::antenna_map::AntennaMap;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+const DAY: u8 = 8;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = DAY;
+    type Input = AntennaMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<AntennaMap, ParseError> {
+        AntennaMap::parse(input)
+    }
+    fn part_1(input: &AntennaMap) -> usize {
+        input.count_unique_antinode_locations()
+    }
+    fn part_2(input: &AntennaMap) -> usize {
+        input.count_unique_extended_antinode_locations()
+    }
+}
@@ -1,39 +1,35 @@
+use crate::day08::DAY;
+use crate::geometry::{Point, Vector};
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::render::palette::Palette;
+use crate::render::Snapshot;
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 
 pub struct AntennaMap {
-    width: usize,
-    height: usize,
+    grid: Grid<char>,
     positions_by_frequency: HashMap<char, Vec<Point>>,
 }
 
-pub(super) type Point = (usize, usize);
-pub(super) type Vector = (isize, isize);
-
 impl AntennaMap {
-    pub fn parse(string: &str) -> AntennaMap {
-        let mut width = 0;
-        let mut height = 0;
+    pub fn parse(string: &str) -> Result<AntennaMap, ParseError> {
+        let grid = Grid::parse(DAY, string)?;
         let mut positions_by_frequency = HashMap::new();
-        for (y, line) in string.lines().enumerate() {
-            width = line.len();
-            height += 1;
-            for (x, c) in line.chars().enumerate() {
-                match c {
-                    '.' => {}
-                    _ => match positions_by_frequency.entry(c) {
-                        Entry::Occupied(positions) => positions.into_mut(),
-                        Entry::Vacant(entry) => entry.insert(vec![]),
-                    }
-                    .push((x, y)),
+        for (point, c) in grid.iter() {
+            match c {
+                '.' => {}
+                _ => match positions_by_frequency.entry(*c) {
+                    Entry::Occupied(positions) => positions.into_mut(),
+                    Entry::Vacant(entry) => entry.insert(vec![]),
                 }
+                .push(point),
             }
         }
-        AntennaMap {
-            width,
-            height,
+        Ok(AntennaMap {
+            grid,
             positions_by_frequency,
-        }
+        })
     }
     pub fn count_unique_antinode_locations(&self) -> usize {
         let antinodes: HashSet<Point> = self.iter_antinodes().collect();
@@ -45,8 +41,7 @@ impl AntennaMap {
     }
     fn iter_antinodes(&self) -> impl Iterator<Item = Point> + use<'_> {
         self.all_antenna_combinations()
-            .flat_map(|(left, right)| antinodes_of_points(left, right))
-            .filter(|point| self.is_in_grid(*point))
+            .flat_map(|(left, right)| self.antinodes_of_points(left, right))
     }
     fn iter_extended_antinodes(&self) -> impl Iterator<Item = Point> + use<'_> {
         self.all_antenna_combinations()
@@ -57,36 +52,52 @@ impl AntennaMap {
             .values()
             .flat_map(all_combinations_for_frequency)
     }
-    fn is_in_grid(&self, point: Point) -> bool {
-        let (x, y) = point;
-        x < self.width && y < self.height
+    fn antinodes_of_points(&self, left: Point, right: Point) -> Vec<Point> {
+        let diff = right - left;
+        [self.grid.offset(left, -diff), self.grid.offset(right, diff)]
+            .into_iter()
+            .flatten()
+            .collect()
     }
     fn extended_antinodes_of_points(&self, left: Point, right: Point) -> Vec<Point> {
-        let (x1, y1) = to_vector(left);
-        let (x2, y2) = to_vector(right);
-        let xdiff = x2 - x1;
-        let ydiff = y2 - y1;
+        let diff = right - left;
         let mut antinodes = vec![left, right];
-        self.extend_antinodes((x1, y1), (-xdiff, -ydiff), &mut antinodes);
-        self.extend_antinodes((x2, y2), (xdiff, ydiff), &mut antinodes);
+        self.extend_antinodes(left, -diff, &mut antinodes);
+        self.extend_antinodes(right, diff, &mut antinodes);
         antinodes
     }
-    fn extend_antinodes(&self, origin: Vector, diff: Vector, antinodes: &mut Vec<Point>) {
-        let (mut x, mut y) = origin;
-        let (dx, dy) = diff;
-        loop {
-            x += dx;
-            y += dy;
-            let point = to_point((x, y));
-            if self.is_in_grid(point) {
-                antinodes.push(point);
-            } else {
-                return;
-            }
+    fn extend_antinodes(&self, origin: Point, diff: Vector, antinodes: &mut Vec<Point>) {
+        let mut point = origin;
+        while let Some(next) = self.grid.offset(point, diff) {
+            antinodes.push(next);
+            point = next;
         }
     }
+    fn plot(&self, antinodes: &HashSet<Point>) -> Grid<char> {
+        let [width, height] = self.grid.size();
+        Grid::from_fn(width, height, |point| match self.grid[point] {
+            '.' if antinodes.contains(&point) => '#',
+            tile => tile,
+        })
+    }
 }
 
+impl Snapshot for AntennaMap {
+    fn snapshot(&self, part: u8) -> Grid<char> {
+        let antinodes: HashSet<Point> = match part {
+            1 => self.iter_antinodes().collect(),
+            _ => self.iter_extended_antinodes().collect(),
+        };
+        self.plot(&antinodes)
+    }
+
+    fn palette(&self) -> Palette {
+        let frequencies = self.positions_by_frequency.keys().copied();
+        Palette::distinct([15, 15, 35], frequencies).with("#", [255, 255, 255])
+    }
+}
+
+#[allow(clippy::ptr_arg)]
 fn all_combinations_for_frequency(
     positions: &Vec<Point>,
 ) -> impl Iterator<Item = (Point, Point)> + use<'_> {
@@ -94,32 +105,12 @@ fn all_combinations_for_frequency(
         .flat_map(move |i| ((i + 1)..positions.len()).map(move |j| (positions[i], positions[j])))
 }
 
-fn antinodes_of_points(left: Point, right: Point) -> Vec<Point> {
-    let (x1, y1) = to_vector(left);
-    let (x2, y2) = to_vector(right);
-    let xdiff = x2 - x1;
-    let ydiff = y2 - y1;
-    let point_0 = (x1 - xdiff, y1 - ydiff);
-    let point_3 = (x2 + xdiff, y2 + ydiff);
-    vec![to_point(point_0), to_point(point_3)]
-}
-
-fn to_vector(point: Point) -> Vector {
-    let (left, right) = point;
-    (left as isize, right as isize)
-}
-
-fn to_point(vector: Vector) -> Point {
-    let (left, right) = vector;
-    let a = 0_usize.wrapping_add_signed(left);
-    let b = 0_usize.wrapping_add_signed(right);
-    (a, b)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::day08::antenna_map::{AntennaMap, Point};
+    use crate::day08::antenna_map::AntennaMap;
+    use crate::geometry::Point;
     use crate::input::input_to_string;
+    use crate::render::Snapshot;
     use insta::assert_snapshot;
     use std::collections::HashMap;
 
@@ -138,17 +129,23 @@ mod tests {
     #[test]
     fn can_count_antinodes_for_example() {
         let input = input_to_string("day08/example.txt").unwrap();
-        let map = AntennaMap::parse(input.as_str());
+        let map = AntennaMap::parse(input.as_str()).unwrap();
         assert_eq!(map.count_unique_antinode_locations(), 14)
     }
 
     #[test]
     fn can_count_extendedantinodes_for_example() {
         let input = input_to_string("day08/example.txt").unwrap();
-        let map = AntennaMap::parse(input.as_str());
+        let map = AntennaMap::parse(input.as_str()).unwrap();
         assert_eq!(map.count_unique_extended_antinode_locations(), 34)
     }
 
+    #[test]
+    fn can_refuse_ragged_map() {
+        let error = AntennaMap::parse("..a.\n..a").err().unwrap();
+        assert_eq!([error.line, error.column], [2, 1])
+    }
+
     #[test]
     fn can_find_antenna_combinations() {
         assert_eq!(
@@ -158,41 +155,19 @@ mod tests {
     }
 
     fn plot_map(map_string: &str) -> String {
-        let map = AntennaMap::parse(map_string);
-        let mut point_to_char = index_frequency_by_position(&map);
-        map.iter_antinodes().for_each(|point| {
-            point_to_char.entry(point).or_insert('#');
-        });
-        plot_map_with_chars(&map, point_to_char)
+        AntennaMap::parse(map_string)
+            .unwrap()
+            .snapshot(1)
+            .to_string()
     }
     fn plot_map_extended(map_string: &str) -> String {
-        let map = AntennaMap::parse(map_string);
-        let mut point_to_char = index_frequency_by_position(&map);
-        map.iter_extended_antinodes().for_each(|point| {
-            point_to_char.entry(point).or_insert('#');
-        });
-        plot_map_with_chars(&map, point_to_char)
-    }
-    fn plot_map_with_chars(map: &AntennaMap, point_to_char: HashMap<Point, char>) -> String {
-        let mut str = "".to_string();
-        for y in 0..map.height {
-            for x in 0..map.width {
-                str.push(*point_to_char.get(&(x, y)).unwrap_or(&'.'));
-            }
-            str.push('\n');
-        }
-        str
-    }
-    fn index_frequency_by_position(map: &AntennaMap) -> HashMap<Point, char> {
-        map.positions_by_frequency
-            .iter()
-            .flat_map(|(frequency, positions)| {
-                positions.iter().map(|position| (*position, *frequency))
-            })
-            .collect()
+        AntennaMap::parse(map_string)
+            .unwrap()
+            .snapshot(2)
+            .to_string()
     }
     fn all_antenna_combinations_by_number(map_string: &str) -> Vec<(usize, usize)> {
-        let map = AntennaMap::parse(map_string);
+        let map = AntennaMap::parse(map_string).unwrap();
         let number_by_position = index_antenna_number_by_position(&map);
         map.all_antenna_combinations()
             .map(|(left, right)| {
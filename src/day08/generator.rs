@@ -0,0 +1,19 @@
+use crate::generate::{Generated, Rng};
+
+const FREQUENCIES: &[char] = &['a', 'A', 'b', 'B', 'z', '0', '7'];
+
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.max(2);
+    let mut input = String::with_capacity((side + 1) * side);
+    for _ in 0..side {
+        for _ in 0..side {
+            input.push(if rng.chance(5) {
+                rng.pick(FREQUENCIES)
+            } else {
+                '.'
+            });
+        }
+        input.push('\n');
+    }
+    Generated::unknown(input)
+}
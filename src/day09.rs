@@ -1,2 +1,35 @@
 pub mod disk_map;
 pub mod file_system;
+pub mod generator;
+pub mod oracle;
+
+use crate::day09::disk_map::DiskMap;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+const DAY: u8 = 9;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = DAY;
+    type Input = DiskMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<DiskMap, ParseError> {
+        DiskMap::parse(input)
+    }
+    fn part_1(input: &DiskMap) -> usize {
+        input
+            .build_file_system()
+            .compact_splitting_files()
+            .checksum()
+    }
+    fn part_2(input: &DiskMap) -> usize {
+        input
+            .compact_fitting_into_spaces()
+            .build_file_system()
+            .checksum()
+    }
+}
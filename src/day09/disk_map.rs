@@ -1,19 +1,21 @@
 use crate::day09::file_system::FileSystem;
-use std::str::FromStr;
+use crate::day09::DAY;
+use crate::parse::ParseError;
 
+#[derive(Debug, Eq, PartialEq)]
 pub struct DiskMap {
     length: usize,
     files: Vec<File>,
     spaces: Vec<Space>,
 }
 
-#[derive(Clone)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 struct Space {
     index: usize,
     length: usize,
 }
 
-#[derive(Clone)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 struct File {
     id: usize,
     index: usize,
@@ -21,11 +23,17 @@ struct File {
 }
 
 impl DiskMap {
-    pub fn parse(string: &str) -> DiskMap {
+    pub fn parse(string: &str) -> Result<DiskMap, ParseError> {
         let sizes: Vec<usize> = string
-            .chars()
-            .flat_map(|char| usize::from_str(char.to_string().as_str()))
-            .collect();
+            .trim_end()
+            .char_indices()
+            .map(|(i, char)| {
+                char.to_digit(10).map(|size| size as usize).ok_or_else(|| {
+                    let text = &string[i..i + char.len_utf8()];
+                    ParseError::at(DAY, string, text, "expected a digit")
+                })
+            })
+            .collect::<Result<_, _>>()?;
         let mut files: Vec<File> = Vec::with_capacity(sizes.len() / 2 + 1);
         let mut spaces: Vec<Space> = Vec::with_capacity(sizes.len() / 2);
         let mut pos = 0;
@@ -46,11 +54,11 @@ impl DiskMap {
                 pos += space_size;
             }
         }
-        DiskMap {
+        Ok(DiskMap {
             length: pos,
             files,
             spaces,
-        }
+        })
     }
     pub fn build_file_system(&self) -> FileSystem {
         let mut fs = FileSystem::with_capacity(self.length);
@@ -80,25 +88,59 @@ impl DiskMap {
             spaces,
         }
     }
+    pub fn print(&self) -> String {
+        let mut string = String::with_capacity(self.files.len() * 2);
+        for (i, file) in self.files.iter().enumerate() {
+            string.push_str(&file.length.to_string());
+            if let Some(space) = self.spaces.get(i) {
+                string.push_str(&space.length.to_string());
+            }
+        }
+        string
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::day09::file_system::tests as fs;
+    use crate::property::{assert_round_trips, Shrink};
 
     #[test]
     fn can_compact_fitting_into_spaces() {
-        let map = DiskMap::parse("14213");
+        let map = DiskMap::parse("14213").unwrap();
         let fs = map.compact_fitting_into_spaces().build_file_system();
-        assert_eq!(fs::print(&fs), "0222.11")
+        assert_eq!(fs.print(), "0222.11")
+    }
+
+    #[test]
+    fn can_parse_with_trailing_newline() {
+        let fs = DiskMap::parse("12345\n").unwrap().build_file_system();
+        assert_eq!(fs.print(), "0..111....22222")
+    }
+
+    #[test]
+    fn can_refuse_non_digit() {
+        let error = DiskMap::parse("123-5").err().unwrap();
+        assert_eq!([error.line, error.column], [1, 4]);
+        assert_eq!(error.text, "-")
+    }
+
+    #[test]
+    fn can_print_disk_map() {
+        let map = DiskMap::parse("2333133121414131402\n").unwrap();
+        assert_eq!(map.print(), "2333133121414131402")
+    }
+
+    #[test]
+    fn can_round_trip_generated_disk_maps() {
+        assert_round_trips(DAY, Shrink::Chars, DiskMap::parse, DiskMap::print)
     }
 
     #[test]
     fn can_compact_fitting_into_spaces_for_example() {
         let string = "2333133121414131402";
-        let map = DiskMap::parse(string);
+        let map = DiskMap::parse(string).unwrap();
         let fs = map.compact_fitting_into_spaces().build_file_system();
-        assert_eq!(fs::print(&fs), "00992111777.44.333....5555.6666.....8888")
+        assert_eq!(fs.print(), "00992111777.44.333....5555.6666.....8888")
     }
 }
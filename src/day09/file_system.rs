@@ -47,44 +47,52 @@ impl FileSystem {
             .map(|(index, id)| if *id < 0 { 0 } else { index * *id as usize })
             .sum()
     }
+    pub fn print(&self) -> String {
+        self.file_ids
+            .iter()
+            .map(|id| match id {
+                -1 => '.'.to_string(),
+                id => id.to_string(),
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
-pub(crate) mod tests {
+mod tests {
     use crate::day09::disk_map::DiskMap;
-    use crate::day09::file_system::FileSystem;
 
     #[test]
     fn can_build_file_system() {
-        let map = DiskMap::parse("12345");
-        assert_eq!(print(&map.build_file_system()), "0..111....22222");
+        let map = DiskMap::parse("12345").unwrap();
+        assert_eq!(map.build_file_system().print(), "0..111....22222");
     }
 
     #[test]
     fn can_compute_checksum() {
-        let fs = DiskMap::parse("12345").build_file_system();
+        let fs = DiskMap::parse("12345").unwrap().build_file_system();
         assert_eq!(fs.checksum(), 3 + 4 + 5 + 2 * (10 + 11 + 12 + 13 + 14))
     }
 
     #[test]
     fn can_compact_splitting_files() {
-        let fs = DiskMap::parse("12345").build_file_system();
-        assert_eq!(print(&fs.compact_splitting_files()), "022111222")
+        let fs = DiskMap::parse("12345").unwrap().build_file_system();
+        assert_eq!(fs.compact_splitting_files().print(), "022111222")
     }
 
     #[test]
     fn can_build_example_file_system() {
         let string = "2333133121414131402";
-        let fs = DiskMap::parse(string).build_file_system();
-        assert_eq!(print(&fs), "00...111...2...333.44.5555.6666.777.888899");
+        let fs = DiskMap::parse(string).unwrap().build_file_system();
+        assert_eq!(fs.print(), "00...111...2...333.44.5555.6666.777.888899");
     }
 
     #[test]
     fn can_compact_splitting_files_for_example() {
         let string = "2333133121414131402";
-        let fs = DiskMap::parse(string).build_file_system();
+        let fs = DiskMap::parse(string).unwrap().build_file_system();
         assert_eq!(
-            print(&fs.compact_splitting_files()),
+            fs.compact_splitting_files().print(),
             "0099811188827773336446555566"
         );
     }
@@ -92,18 +100,7 @@ pub(crate) mod tests {
     #[test]
     fn can_compute_example_checksum_after_compact_splitting_files() {
         let string = "2333133121414131402";
-        let fs = DiskMap::parse(string).build_file_system();
+        let fs = DiskMap::parse(string).unwrap().build_file_system();
         assert_eq!(fs.compact_splitting_files().checksum(), 1928);
     }
-
-    pub fn print(file_system: &FileSystem) -> String {
-        file_system
-            .file_ids
-            .iter()
-            .map(|id| match id {
-                -1 => '.'.to_string(),
-                id => id.to_string(),
-            })
-            .collect()
-    }
 }
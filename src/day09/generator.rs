@@ -0,0 +1,13 @@
+use crate::generate::{Generated, Rng};
+
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::with_capacity(size * 2);
+    for file in 0..size.max(1) {
+        if file > 0 {
+            input.push(char::from(b'0' + rng.range(0..=9) as u8));
+        }
+        input.push(char::from(b'0' + rng.range(1..=9) as u8));
+    }
+    input.push('\n');
+    Generated::unknown(input)
+}
@@ -0,0 +1,78 @@
+use crate::day09::disk_map::DiskMap;
+use crate::solution::Answer;
+
+pub fn compact_splitting_files(input: &str) -> Option<Answer> {
+    let map = DiskMap::parse(input).ok()?;
+    let checksum = map.build_file_system().compact_splitting_files().checksum();
+    Some(checksum.into())
+}
+
+pub fn compact_fitting_into_spaces(input: &str) -> Option<Answer> {
+    let map = DiskMap::parse(input).ok()?;
+    let checksum = map
+        .compact_fitting_into_spaces()
+        .build_file_system()
+        .checksum();
+    Some(checksum.into())
+}
+
+pub fn simulate_splitting_files(input: &str) -> Option<Answer> {
+    let mut blocks = expand_blocks(input)?;
+    let (mut free, mut last) = (0, blocks.len());
+    loop {
+        while free < blocks.len() && blocks[free].is_some() {
+            free += 1;
+        }
+        while last > 0 && blocks[last - 1].is_none() {
+            last -= 1;
+        }
+        if last == 0 || free >= last - 1 {
+            break;
+        }
+        blocks.swap(free, last - 1);
+    }
+    Some(checksum(&blocks).into())
+}
+
+pub fn simulate_fitting_into_spaces(input: &str) -> Option<Answer> {
+    let mut blocks = expand_blocks(input)?;
+    let files = blocks.iter().flatten().max().map_or(0, |id| id + 1);
+    for id in (0..files).rev() {
+        let Some(start) = blocks.iter().position(|block| *block == Some(id)) else {
+            continue;
+        };
+        let length = blocks[start..]
+            .iter()
+            .take_while(|block| **block == Some(id))
+            .count();
+        let space = (0..start).find(|space| {
+            blocks[*space..*space + length]
+                .iter()
+                .all(|block| block.is_none())
+        });
+        if let Some(space) = space {
+            for i in 0..length {
+                blocks.swap(space + i, start + i);
+            }
+        }
+    }
+    Some(checksum(&blocks).into())
+}
+
+fn expand_blocks(input: &str) -> Option<Vec<Option<usize>>> {
+    DiskMap::parse(input).ok()?;
+    let mut blocks = vec![];
+    for (i, size) in input.trim_end().chars().enumerate() {
+        let block = if i % 2 == 0 { Some(i / 2) } else { None };
+        blocks.extend((0..size.to_digit(10)?).map(|_| block));
+    }
+    Some(blocks)
+}
+
+fn checksum(blocks: &[Option<usize>]) -> usize {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(i, block)| i * block.unwrap_or(0))
+        .sum()
+}
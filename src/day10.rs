@@ -1 +1,27 @@
+pub mod generator;
 pub mod hiking_map;
+
+use crate::day10::hiking_map::HikingMap;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+const DAY: u8 = 10;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = DAY;
+    type Input = HikingMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<HikingMap, ParseError> {
+        HikingMap::parse(input)
+    }
+    fn part_1(input: &HikingMap) -> usize {
+        input.sum_trailhead_scores()
+    }
+    fn part_2(input: &HikingMap) -> usize {
+        input.sum_trailhead_ratings()
+    }
+}
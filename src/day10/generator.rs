@@ -0,0 +1,18 @@
+use crate::generate::{Generated, Rng};
+
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.max(1);
+    let mut input = String::with_capacity((side + 1) * side);
+    for y in 0..side {
+        for x in 0..side {
+            let height = if rng.chance(20) {
+                rng.below(10)
+            } else {
+                (x + y) % 10
+            };
+            input.push(char::from(b'0' + height as u8));
+        }
+        input.push('\n');
+    }
+    Generated::unknown(input)
+}
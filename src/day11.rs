@@ -1,3 +1,33 @@
 mod blink;
 mod digits;
+pub mod generator;
+pub mod oracle;
 pub mod stones;
+
+pub use blink::blink_stone;
+
+use crate::day11::stones::Stones;
+use crate::observe;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+const DAY: u8 = 11;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = DAY;
+    type Input = Stones;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Stones, ParseError> {
+        Stones::parse(input)
+    }
+    fn part_1(input: &Stones) -> usize {
+        input.count_stones_after_blinks_observed(25, observe::current())
+    }
+    fn part_2(input: &Stones) -> usize {
+        input.count_stones_after_blinks_observed(75, observe::current())
+    }
+}
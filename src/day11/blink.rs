@@ -4,7 +4,7 @@ pub fn blink_stone(stone: usize) -> Vec<usize> {
     let digits = count_digits(stone);
     match stone {
         0 => vec![1],
-        even if digits % 2 == 0 => split_even_digits(even, digits),
+        even if digits.is_multiple_of(2) => split_even_digits(even, digits),
         other => vec![other * 2024],
     }
 }
@@ -12,11 +12,12 @@ pub fn blink_stone(stone: usize) -> Vec<usize> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day11::stones::Stones;
 
     #[test]
     fn can_blink_once_first_example() {
         assert_eq!(
-            print(&blink_times(1, &[0, 1, 10, 99, 999])),
+            Stones::new(blink_times(1, &[0, 1, 10, 99, 999])).print(),
             "1 2024 1 0 9 9 2021976"
         )
     }
@@ -27,7 +28,7 @@ mod tests {
         let blinks: Vec<String> = [0, 1, 2, 3, 4, 5, 6]
             .iter()
             .map(|blinks| blink_times(*blinks, &stones))
-            .map(|stones| print(&stones))
+            .map(|stones| Stones::new(stones).print())
             .collect();
         assert_eq!(
             blinks,
@@ -49,7 +50,7 @@ mod tests {
         let blinks: Vec<String> = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]
             .iter()
             .map(|blinks| blink_times(*blinks, &stones))
-            .map(|stones| print(&stones))
+            .map(|stones| Stones::new(stones).print())
             .collect();
         assert_eq!(
             blinks,
@@ -83,11 +84,4 @@ mod tests {
         }
         stones
     }
-    fn print(stones: &[usize]) -> String {
-        stones
-            .iter()
-            .map(|stone| stone.to_string())
-            .collect::<Vec<String>>()
-            .join(" ")
-    }
 }
@@ -0,0 +1,8 @@
+use crate::generate::{Generated, Rng};
+
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let stones: Vec<String> = (0..size.max(1))
+        .map(|_| rng.range(0..=99999).to_string())
+        .collect();
+    Generated::unknown(format!("{}\n", stones.join(" ")))
+}
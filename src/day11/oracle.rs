@@ -0,0 +1,18 @@
+use crate::day11::blink::blink_stone;
+use crate::day11::stones::Stones;
+use crate::solution::Answer;
+
+const BLINKS: usize = 12;
+
+pub fn count_stones(input: &str) -> Option<Answer> {
+    let stones = Stones::parse(input).ok()?;
+    Some(stones.count_stones_after_blinks(BLINKS).into())
+}
+
+pub fn expand_stones(input: &str) -> Option<Answer> {
+    let mut stones = Stones::parse(input).ok()?.stones;
+    for _ in 0..BLINKS {
+        stones = stones.into_iter().flat_map(blink_stone).collect();
+    }
+    Some(stones.len().into())
+}
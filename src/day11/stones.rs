@@ -1,30 +1,54 @@
 use crate::day11::blink::blink_stone;
+use crate::day11::DAY;
+use crate::observe::{Observer, Silent};
+use crate::parse::{parse_number, ParseError};
 use std::collections::HashMap;
-use std::str::FromStr;
 
-#[derive(Clone)]
+const BLINK_TASK: &str = "Blinking";
+
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Stones {
-    stones: Vec<usize>,
+    pub(super) stones: Vec<usize>,
 }
 
 impl Stones {
-    pub fn parse(string: &str) -> Stones {
+    pub fn new(stones: Vec<usize>) -> Stones {
+        Stones { stones }
+    }
+    pub fn parse(string: &str) -> Result<Stones, ParseError> {
         let stones = string
             .split_whitespace()
-            .map(|stone| usize::from_str(stone).unwrap())
-            .collect();
-        Stones { stones }
+            .map(|stone| parse_number(DAY, string, stone))
+            .collect::<Result<_, _>>()?;
+        Ok(Stones { stones })
+    }
+    pub fn print(&self) -> String {
+        self.stones
+            .iter()
+            .map(|stone| stone.to_string())
+            .collect::<Vec<String>>()
+            .join(" ")
     }
     pub fn count_stones_after_blinks(&self, times: usize) -> usize {
-        count_stones_with_blinks(times, &self.stones)
+        self.count_stones_after_blinks_observed(times, &Silent)
+    }
+    pub fn count_stones_after_blinks_observed(
+        &self,
+        times: usize,
+        observer: &dyn Observer,
+    ) -> usize {
+        count_stones_with_blinks(times, &self.stones, observer)
     }
 }
 
-fn count_stones_with_blinks(blinks: usize, stones: &Vec<usize>) -> usize {
+fn count_stones_with_blinks(blinks: usize, stones: &Vec<usize>, observer: &dyn Observer) -> usize {
     let mut stone_counts = to_stone_counts(stones);
-    for _ in 0..blinks {
+    observer.started(BLINK_TASK, blinks);
+    for blink in 0..blinks {
         stone_counts = blink_with_counts(&stone_counts);
+        observer.progressed(BLINK_TASK, blink + 1, blinks);
     }
+    observer.finished(BLINK_TASK);
     stone_counts.values().sum()
 }
 
@@ -49,22 +73,53 @@ fn blink_with_counts(stone_counts: &HashMap<usize, usize>) -> HashMap<usize, usi
 #[cfg(test)]
 mod tests {
     use crate::day11::stones::Stones;
+    use crate::day11::DAY;
+    use crate::observe::tests::Recorder;
+    use crate::property::{assert_round_trips, Shrink};
 
     #[test]
     fn can_parse_stones() {
-        let stones = Stones::parse("0 1 10 99 999");
-        assert_eq!(print(&stones), "0 1 10 99 999")
+        let stones = Stones::parse("0 1 10 99 999").unwrap();
+        assert_eq!(stones.print(), "0 1 10 99 999")
+    }
+
+    #[test]
+    fn can_round_trip_generated_stones() {
+        assert_round_trips(DAY, Shrink::Words, Stones::parse, Stones::print)
+    }
+
+    #[test]
+    fn can_refuse_negative_stone() {
+        let error = Stones::parse("0 1 -10").err().unwrap();
+        assert_eq!([error.line, error.column], [1, 5]);
+        assert_eq!(error.text, "-10")
     }
 
     #[test]
     fn can_count_stones_after_25_blinks_second_example() {
-        let stones = Stones::parse("125 17");
+        let stones = Stones::parse("125 17").unwrap();
         assert_eq!(count_stones_after_blinks(&stones, 25), 55312)
     }
 
+    #[test]
+    fn can_report_each_blink() {
+        let stones = Stones::parse("125 17").unwrap();
+        let recorder = Recorder::default();
+        assert_eq!(stones.count_stones_after_blinks_observed(2, &recorder), 4);
+        assert_eq!(
+            recorder.events(),
+            [
+                "Blinking started 2",
+                "Blinking 1/2",
+                "Blinking 2/2",
+                "Blinking finished"
+            ]
+        )
+    }
+
     #[test]
     fn can_find_number_of_stones_sequence() {
-        let stones = Stones::parse("125 17");
+        let stones = Stones::parse("125 17").unwrap();
         let sequence: Vec<usize> = (0..=25)
             .map(|blinks| count_stones_after_blinks(&stones, blinks))
             .collect();
@@ -79,7 +134,7 @@ mod tests {
 
     #[test]
     fn can_find_number_of_stones_sequence_for_one_stone() {
-        let stones = Stones::parse("125");
+        let stones = Stones::parse("125").unwrap();
         let sequence: Vec<usize> = (0..=30)
             .map(|blinks| count_stones_after_blinks(&stones, blinks))
             .collect();
@@ -94,7 +149,7 @@ mod tests {
 
     #[test]
     fn can_find_number_of_stones_sequence_for_zero() {
-        let stones = Stones::parse("0");
+        let stones = Stones::parse("0").unwrap();
         let sequence: Vec<usize> = (0..=30)
             .map(|blinks| count_stones_after_blinks(&stones, blinks))
             .collect();
@@ -110,13 +165,4 @@ mod tests {
     fn count_stones_after_blinks(stones: &Stones, blinks: usize) -> usize {
         stones.count_stones_after_blinks(blinks)
     }
-
-    fn print(stones: &Stones) -> String {
-        stones
-            .stones
-            .iter()
-            .map(|stone| stone.to_string())
-            .collect::<Vec<String>>()
-            .join(" ")
-    }
 }
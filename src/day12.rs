@@ -1,4 +1,29 @@
 mod edge;
 pub mod garden_map;
-mod point;
+pub mod generator;
 mod region;
+
+use crate::day12::garden_map::GardenMap;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+const DAY: u8 = 12;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = DAY;
+    type Input = GardenMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<GardenMap, ParseError> {
+        GardenMap::parse(input)
+    }
+    fn part_1(input: &GardenMap) -> usize {
+        input.sum_fencing_price()
+    }
+    fn part_2(input: &GardenMap) -> usize {
+        input.sum_fencing_price_bulk_discount()
+    }
+}
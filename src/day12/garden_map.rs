@@ -1,30 +1,20 @@
-use crate::day12::edge::{along_dim_index, edge, Edge};
-use crate::day12::point::Point;
 use crate::day12::region::build_regions;
+use crate::day12::DAY;
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::render::palette::Palette;
+use crate::render::Snapshot;
 
+#[derive(Debug, Eq, PartialEq)]
 pub struct GardenMap {
-    plots: Vec<Vec<char>>,
-    width: usize,
-    height: usize,
+    plots: Grid<char>,
 }
 
 impl GardenMap {
-    pub fn parse(string: &str) -> GardenMap {
-        let mut plots = vec![];
-        for line in string.lines() {
-            let mut line_plots = Vec::with_capacity(line.len());
-            for c in line.chars() {
-                line_plots.push(c);
-            }
-            plots.push(line_plots);
-        }
-        let width = plots.first().map(|line| line.len()).unwrap_or(0);
-        let height = plots.len();
-        GardenMap {
-            plots,
-            width,
-            height,
-        }
+    pub fn parse(string: &str) -> Result<GardenMap, ParseError> {
+        let plots = Grid::parse(DAY, string)?;
+        Ok(GardenMap { plots })
     }
     pub fn sum_fencing_price(&self) -> usize {
         build_regions(self)
@@ -38,52 +28,37 @@ impl GardenMap {
             .map(|region| region.fencing_price_bulk_discount())
             .sum()
     }
+    pub fn print(&self) -> String {
+        self.plots.to_string()
+    }
     pub(crate) fn plant_at(&self, point: Point) -> char {
-        let [x, y] = point;
-        self.plots[y][x]
+        self.plots[point]
     }
-    pub(crate) fn is_on_map(&self, point: Point) -> bool {
-        let [x, y] = point;
-        x < self.width && y < self.height
+    pub(crate) fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        self.plots.step(point, direction)
     }
     pub(crate) fn points(&self) -> impl Iterator<Item = Point> + use<'_> {
-        (0..self.height).flat_map(|y| (0..self.width).map(move |x| [x, y]))
-    }
-    pub(crate) fn adjacent_edge(&self, from: &Edge, direction: isize) -> Option<Edge> {
-        let along_dim = along_dim_index(from);
-        let new_value = from[0][along_dim].wrapping_add_signed(direction);
-        let mut new_edge = *from;
-        new_edge[0][along_dim] = new_value;
-        new_edge[1][along_dim] = new_value;
-        if self.is_outside_limits(along_dim, new_value) {
-            None
-        } else {
-            Some(new_edge)
-        }
-    }
-    pub(crate) fn obstructing_edges(&self, from: &Edge, direction: isize) -> Vec<Edge> {
-        let along_dim = along_dim_index(from);
-        let mut end_0 = from[0];
-        end_0[along_dim] = end_0[along_dim].wrapping_add_signed(direction);
-        let mut end_1 = from[1];
-        end_1[along_dim] = end_1[along_dim].wrapping_add_signed(direction);
-        vec![edge(from[0], end_0), edge(from[1], end_1)]
-    }
-    fn is_outside_limits(&self, dim_index: usize, value: usize) -> bool {
-        value > self.dim_index_limit(dim_index) && value < usize::MAX
-    }
-    fn dim_index_limit(&self, dim_index: usize) -> usize {
-        if dim_index == 0 {
-            self.width
-        } else {
-            self.height
-        }
+        self.plots.points()
+    }
+}
+
+impl Snapshot for GardenMap {
+    fn snapshot(&self, _part: u8) -> Grid<char> {
+        self.plots.clone()
+    }
+
+    fn palette(&self) -> Palette {
+        let mut plants: Vec<char> = self.plots.iter().map(|(_, plant)| *plant).collect();
+        plants.sort();
+        Palette::distinct([15, 15, 35], plants)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::day12::garden_map::GardenMap;
+    use crate::day12::DAY;
+    use crate::property::{assert_round_trips, Shrink};
 
     #[test]
     fn can_parse_map() {
@@ -92,8 +67,19 @@ mod tests {
             BBCD\n\
             BBCC\n\
             EEEC\n";
-        let map = GardenMap::parse(string);
-        assert_eq!(print(&map), string)
+        let map = GardenMap::parse(string).unwrap();
+        assert_eq!(map.print(), string)
+    }
+
+    #[test]
+    fn can_round_trip_generated_gardens() {
+        assert_round_trips(DAY, Shrink::Lines, GardenMap::parse, GardenMap::print)
+    }
+
+    #[test]
+    fn can_refuse_ragged_map() {
+        let error = GardenMap::parse("AAAA\nBBC\n").err().unwrap();
+        assert_eq!([error.line, error.column], [2, 1])
     }
 
     #[test]
@@ -103,7 +89,7 @@ mod tests {
             BBCD\n\
             BBCC\n\
             EEEC\n";
-        let map = GardenMap::parse(string);
+        let map = GardenMap::parse(string).unwrap();
         assert_eq!(map.sum_fencing_price(), 140)
     }
 
@@ -114,7 +100,7 @@ mod tests {
             BBCD\n\
             BBCC\n\
             EEEC\n";
-        let map = GardenMap::parse(string);
+        let map = GardenMap::parse(string).unwrap();
         assert_eq!(map.sum_fencing_price_bulk_discount(), 80)
     }
 
@@ -126,7 +112,7 @@ mod tests {
             EEEEE\n\
             EXXXX\n\
             EEEEE\n";
-        let map = GardenMap::parse(string);
+        let map = GardenMap::parse(string).unwrap();
         assert_eq!(map.sum_fencing_price_bulk_discount(), 236)
     }
 
@@ -139,19 +125,7 @@ mod tests {
             ABBAAA\n\
             ABBAAA\n\
             AAAAAA\n";
-        let map = GardenMap::parse(string);
+        let map = GardenMap::parse(string).unwrap();
         assert_eq!(map.sum_fencing_price_bulk_discount(), 368)
     }
-
-    fn print(map: &GardenMap) -> String {
-        let mut str = String::new();
-        for line in map.plots.iter() {
-            str.push_str(&print_line(line));
-            str.push('\n');
-        }
-        str
-    }
-    fn print_line(line: &[char]) -> String {
-        line.iter().collect()
-    }
 }
@@ -0,0 +1,26 @@
+use crate::generate::{Generated, Rng};
+
+const PLANTS: &[char] = &['A', 'B', 'C', 'E', 'I', 'R', 'V', 'X'];
+
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.max(1);
+    let mut rows: Vec<Vec<char>> = vec![];
+    for y in 0..side {
+        let mut row: Vec<char> = vec![];
+        for x in 0..side {
+            let plant = match rng.below(10) {
+                0..=3 if x > 0 => row[x - 1],
+                4..=7 if y > 0 => rows[y - 1][x],
+                _ => rng.pick(PLANTS),
+            };
+            row.push(plant);
+        }
+        rows.push(row);
+    }
+    let mut input = String::with_capacity((side + 1) * side);
+    for row in rows {
+        input.extend(row);
+        input.push('\n');
+    }
+    Generated::unknown(input)
+}
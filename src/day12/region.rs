@@ -1,6 +1,8 @@
-use crate::day12::edge::{adjacent_edges, edge, Edge};
+use crate::day12::edge::{count_sides, Edge};
 use crate::day12::garden_map::GardenMap;
-use crate::day12::point::{adjacent_points, Point};
+use crate::geometry::{Direction, Point};
+use crate::search::breadth_first;
+use crate::trace::span;
 use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Eq, PartialEq)]
@@ -22,6 +24,7 @@ impl Region {
 }
 
 pub fn build_regions(map: &GardenMap) -> Vec<Region> {
+    let _build = span("build regions");
     let mut point_to_region_number = HashMap::new();
     build_regions_and_points(map, &mut point_to_region_number)
 }
@@ -53,82 +56,35 @@ fn map_region_from(
     point_to_region_number: &mut HashMap<Point, usize>,
 ) -> Region {
     let plant = map.plant_at(start_point);
-    let mut region = Region {
+    let points = breadth_first(start_point, |point| {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| map.step(point, direction))
+            .filter(move |adjacent| map.plant_at(*adjacent) == plant)
+    });
+    let edges: HashSet<Edge> = points
+        .iter()
+        .flat_map(|point| Direction::ALL.map(|direction| (point, direction)))
+        .filter(|(point, direction)| {
+            map.step(*point, *direction)
+                .is_none_or(|adjacent| map.plant_at(adjacent) != plant)
+        })
+        .collect();
+    for point in points.iter() {
+        point_to_region_number.insert(point, number);
+    }
+    Region {
         number,
         plant,
-        area: 0,
-        perimeter: 0,
-        sides: 0,
-    };
-    let mut edges = HashSet::new();
-    map_region(
-        start_point,
-        &mut region,
-        map,
-        point_to_region_number,
-        &mut edges,
-    );
-    region.perimeter = edges.len();
-    region.sides = count_sides(&edges, map);
-    region
-}
-
-fn map_region(
-    point: Point,
-    region: &mut Region,
-    map: &GardenMap,
-    point_to_region_number: &mut HashMap<Point, usize>,
-    edges: &mut HashSet<Edge>,
-) {
-    point_to_region_number.insert(point, region.number);
-    region.area += 1;
-    for adjacent in adjacent_points(point) {
-        if !map.is_on_map(adjacent) {
-            edges.insert(edge(point, adjacent));
-            continue;
-        }
-        let adjacent_plant = map.plant_at(adjacent);
-        if region.plant == adjacent_plant {
-            if point_to_region_number.contains_key(&adjacent) {
-                continue;
-            }
-            map_region(adjacent, region, map, point_to_region_number, edges);
-        } else {
-            edges.insert(edge(point, adjacent));
-        }
-    }
-}
-
-fn count_sides(edges: &HashSet<Edge>, map: &GardenMap) -> usize {
-    let mut remaining = edges.clone();
-    let mut count = 0;
-    for edge in edges {
-        if remove_side_edges(edge, map, edges, &mut remaining) {
-            count += 1;
-        }
-    }
-    count
-}
-
-fn remove_side_edges(
-    edge: &Edge,
-    map: &GardenMap,
-    region_edges: &HashSet<Edge>,
-    remaining: &mut HashSet<Edge>,
-) -> bool {
-    if !remaining.remove(edge) {
-        return false;
-    }
-    for adjacent in adjacent_edges(edge, region_edges, map) {
-        remove_side_edges(&adjacent, map, region_edges, remaining);
+        area: points.len(),
+        perimeter: edges.len(),
+        sides: count_sides(&edges),
     }
-    true
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::day12::point::Point;
     use std::cmp::max;
 
     #[test]
@@ -139,7 +95,7 @@ mod tests {
             BBCD\n\
             BBCC\n\
             EEEC\n";
-        let map = GardenMap::parse(string);
+        let map = GardenMap::parse(string).unwrap();
 
         // When
         let mut point_to_region_number = HashMap::new();
@@ -161,7 +117,7 @@ mod tests {
             BBCD\n\
             BBCC\n\
             EEEC\n";
-        let map = GardenMap::parse(string);
+        let map = GardenMap::parse(string).unwrap();
 
         assert_eq!(
             extract_regions(&map, |region| (region.plant, region.area)),
@@ -176,7 +132,7 @@ mod tests {
             BBCD\n\
             BBCC\n\
             EEEC\n";
-        let map = GardenMap::parse(string);
+        let map = GardenMap::parse(string).unwrap();
 
         assert_eq!(
             extract_regions(&map, |region| (region.plant, region.perimeter)),
@@ -191,7 +147,7 @@ mod tests {
             BBCD\n\
             BBCC\n\
             EEEC\n";
-        let map = GardenMap::parse(string);
+        let map = GardenMap::parse(string).unwrap();
 
         assert_eq!(
             extract_regions(&map, |region| (region.plant, region.sides)),
@@ -204,7 +160,7 @@ mod tests {
         let string = "\
             AA\n\
             AA\n";
-        let map = GardenMap::parse(string);
+        let map = GardenMap::parse(string).unwrap();
 
         assert_eq!(
             extract_regions(&map, |region| (region.plant, region.sides)),
@@ -220,7 +176,7 @@ mod tests {
             EEEEE\n\
             EXXXX\n\
             EEEEE\n";
-        let map = GardenMap::parse(string);
+        let map = GardenMap::parse(string).unwrap();
 
         assert_eq!(
             extract_regions(&map, |region| (
@@ -242,7 +198,7 @@ mod tests {
             ABBAAA\n\
             ABBAAA\n\
             AAAAAA\n";
-        let map = GardenMap::parse(string);
+        let map = GardenMap::parse(string).unwrap();
         assert_eq!(
             extract_regions(&map, |region| (
                 region.plant,
@@ -261,14 +217,14 @@ mod tests {
     fn print_region_numbers(point_to_region_number: &HashMap<Point, usize>) -> String {
         let mut width = 0;
         let mut height = 0;
-        for [x, y] in point_to_region_number.keys() {
-            width = max(width, *x);
-            height = max(height, *y);
+        for point in point_to_region_number.keys() {
+            width = max(width, point.x);
+            height = max(height, point.y);
         }
         let mut str = String::new();
         for y in 0..=height {
             for x in 0..=width {
-                let region_number = point_to_region_number[&[x, y]];
+                let region_number = point_to_region_number[&Point::new(x, y)];
                 str.push_str(&region_number.to_string());
             }
             str.push('\n');
@@ -1,3 +1,37 @@
 mod claw_machine;
 pub mod claw_machines;
+pub mod generator;
 mod line_intersection;
+pub mod oracle;
+
+use crate::day13::claw_machines::ClawMachines;
+use crate::parse::ParseError;
+use crate::pool::Pool;
+use crate::solution::Solution;
+
+const DAY: u8 = 13;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = DAY;
+    type Input = ClawMachines;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<ClawMachines, ParseError> {
+        ClawMachines::parse(input)
+    }
+    fn part_1(input: &ClawMachines) -> usize {
+        input.sum_min_tokens()
+    }
+    fn part_2(input: &ClawMachines) -> usize {
+        input.sum_min_tokens_with_unit_conversion()
+    }
+    fn part_1_in_parallel(input: &ClawMachines, pool: &Pool) -> usize {
+        input.sum_min_tokens_in_parallel(pool)
+    }
+    fn part_2_in_parallel(input: &ClawMachines, pool: &Pool) -> usize {
+        input.sum_min_tokens_with_unit_conversion_in_parallel(pool)
+    }
+}
@@ -1,4 +1,5 @@
-use crate::day13::line_intersection::{find_units_along_each_line, Point};
+use crate::day13::line_intersection::find_units_along_each_line;
+use crate::geometry::{Point, Vector};
 
 pub struct ClawMachine {
     pub button_a_vector: ButtonVector,
@@ -12,8 +13,8 @@ impl ClawMachine {
     }
     pub fn min_tokens_to_win_with_inc(&self, prize_inc: usize) -> usize {
         let mut prize = self.prize_location;
-        prize[0] += prize_inc;
-        prize[1] += prize_inc;
+        prize.x += prize_inc;
+        prize.y += prize_inc;
         let a_first = self
             .find_presses(prize, self.button_a_vector, self.button_b_vector)
             .map(|[a, b]| a * 3 + b);
@@ -33,13 +34,14 @@ impl ClawMachine {
         from_origin: ButtonVector,
         to_prize: ButtonVector,
     ) -> Option<[usize; 2]> {
-        let line_1 = [[0, 0], from_origin];
-        let line_2 = [prize, [prize[0] - to_prize[0], prize[1] - to_prize[1]]];
+        let prize = prize.to_vector();
+        let line_1 = [Vector::default(), from_origin];
+        let line_2 = [prize, prize - to_prize];
         find_units_along_each_line(&line_1, &line_2)
     }
 }
 
-pub type ButtonVector = [usize; 2];
+pub type ButtonVector = Vector;
 
 #[cfg(test)]
 mod tests {
@@ -48,9 +50,9 @@ mod tests {
     #[test]
     fn can_find_min_tokens() {
         let machine = ClawMachine {
-            button_a_vector: [94, 34],
-            button_b_vector: [22, 67],
-            prize_location: [8400, 5400],
+            button_a_vector: Vector::new(94, 34),
+            button_b_vector: Vector::new(22, 67),
+            prize_location: Point::new(8400, 5400),
         };
         assert_eq!(machine.min_tokens_to_win(), 280);
     }
@@ -1,70 +1,124 @@
 use crate::day13::claw_machine::ClawMachine;
+use crate::day13::DAY;
+use crate::geometry::Point;
+use crate::parse::{parse_number, ParseError};
+use crate::pool::Pool;
 use regex::Regex;
-use std::str::FromStr;
 
 pub struct ClawMachines {
     machines: Vec<ClawMachine>,
 }
 
 impl ClawMachines {
-    pub fn parse(string: &str) -> ClawMachines {
+    pub fn parse(string: &str) -> Result<ClawMachines, ParseError> {
         let a_regex = Regex::new(r"Button A: X\+(.+), Y\+(.+)").unwrap();
         let b_regex = Regex::new(r"Button B: X\+(.+), Y\+(.+)").unwrap();
         let prize_regex = Regex::new(r"Prize: X=(.+), Y=(.+)").unwrap();
-        let a_vectors = parse_captures(&a_regex, string);
-        let b_vectors = parse_captures(&b_regex, string);
-        let prize_vectors = parse_captures(&prize_regex, string);
-        let machines = (0..a_vectors.len())
-            .map(|i| ClawMachine {
-                button_a_vector: a_vectors[i],
-                button_b_vector: b_vectors[i],
-                prize_location: prize_vectors[i],
+        let machines = string
+            .split("\n\n")
+            .filter(|block| !block.trim().is_empty())
+            .map(|block| {
+                Ok(ClawMachine {
+                    button_a_vector: parse_captures(&a_regex, string, block, "Button A")?
+                        .to_vector(),
+                    button_b_vector: parse_captures(&b_regex, string, block, "Button B")?
+                        .to_vector(),
+                    prize_location: parse_captures(&prize_regex, string, block, "Prize")?,
+                })
             })
-            .collect();
-        ClawMachines { machines }
+            .collect::<Result<_, _>>()?;
+        Ok(ClawMachines { machines })
+    }
+    pub(super) fn iter(&self) -> impl Iterator<Item = &ClawMachine> {
+        self.machines.iter()
     }
     pub fn sum_min_tokens(&self) -> usize {
-        self.machines
-            .iter()
-            .map(|machine| machine.min_tokens_to_win())
-            .sum()
+        self.sum_min_tokens_in_parallel(&Pool::new(1))
     }
     pub fn sum_min_tokens_with_unit_conversion(&self) -> usize {
-        self.machines
-            .iter()
-            .map(|machine| machine.min_tokens_to_win_with_inc(10000000000000))
-            .sum()
+        self.sum_min_tokens_with_unit_conversion_in_parallel(&Pool::new(1))
+    }
+    pub fn sum_min_tokens_in_parallel(&self, pool: &Pool) -> usize {
+        pool.sum(&self.machines, |machine| machine.min_tokens_to_win())
+    }
+    pub fn sum_min_tokens_with_unit_conversion_in_parallel(&self, pool: &Pool) -> usize {
+        pool.sum(&self.machines, |machine| {
+            machine.min_tokens_to_win_with_inc(10000000000000)
+        })
     }
 }
 
-fn parse_captures(regex: &Regex, string: &str) -> Vec<[usize; 2]> {
-    regex
-        .captures_iter(string)
-        .map(|capture| {
-            [
-                usize::from_str(capture.get(1).unwrap().as_str()).unwrap(),
-                usize::from_str(capture.get(2).unwrap().as_str()).unwrap(),
-            ]
-        })
-        .collect()
+fn parse_captures(
+    regex: &Regex,
+    string: &str,
+    block: &str,
+    name: &str,
+) -> Result<Point, ParseError> {
+    let captures = regex.captures(block).ok_or_else(|| {
+        let message = format!("expected a line for {name}");
+        ParseError::at(DAY, string, block.lines().next().unwrap_or(block), &message)
+    })?;
+    Ok(Point::new(
+        parse_number(DAY, string, captures.get(1).unwrap().as_str())?,
+        parse_number(DAY, string, captures.get(2).unwrap().as_str())?,
+    ))
 }
 
 #[cfg(test)]
 mod tests {
     use crate::day13::claw_machines::ClawMachines;
     use crate::input::input_to_string;
+    use crate::pool::Pool;
 
     #[test]
     fn can_sum_tokens_for_example() {
         let string = input_to_string("day13/example.txt").unwrap();
-        let machines = ClawMachines::parse(string.as_str());
+        let machines = ClawMachines::parse(string.as_str()).unwrap();
         assert_eq!(machines.sum_min_tokens(), 480)
     }
 
     #[test]
     fn can_sum_tokens_for_example_with_unit_conversion() {
         let string = input_to_string("day13/example.txt").unwrap();
-        let machines = ClawMachines::parse(string.as_str());
+        let machines = ClawMachines::parse(string.as_str()).unwrap();
         assert_eq!(machines.sum_min_tokens_with_unit_conversion(), 875318608908)
     }
+
+    #[test]
+    fn can_sum_tokens_for_example_in_parallel() {
+        let string = input_to_string("day13/example.txt").unwrap();
+        let machines = ClawMachines::parse(string.as_str()).unwrap();
+        let pool = Pool::new(3);
+        assert_eq!(
+            [
+                machines.sum_min_tokens_in_parallel(&pool),
+                machines.sum_min_tokens_with_unit_conversion_in_parallel(&pool)
+            ],
+            [480, 875318608908]
+        )
+    }
+
+    #[test]
+    fn can_refuse_invalid_number() {
+        let string = "\
+            Button A: X+94, Y+34\n\
+            Button B: X+22, Y+67\n\
+            Prize: X=8400, Y=54o0\n";
+        let error = ClawMachines::parse(string).err().unwrap();
+        assert_eq!([error.line, error.column], [3, 18]);
+        assert_eq!(error.text, "54o0")
+    }
+
+    #[test]
+    fn can_refuse_missing_prize() {
+        let string = "\
+            Button A: X+94, Y+34\n\
+            Button B: X+22, Y+67\n\
+            \n\
+            Button A: X+26, Y+66\n\
+            Button B: X+67, Y+21\n";
+        let error = ClawMachines::parse(string).err().unwrap();
+        assert_eq!([error.line, error.column], [1, 1]);
+        assert_eq!(error.message, "expected a line for Prize")
+    }
 }
@@ -0,0 +1,26 @@
+use crate::generate::{Generated, Rng};
+use std::fmt::Write;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let mut tokens = 0;
+    for machine in 0..size {
+        let (a, b) = loop {
+            let a = [rng.range(10..=99), rng.range(10..=99)];
+            let b = [rng.range(10..=99), rng.range(10..=99)];
+            if a[0] * b[1] != a[1] * b[0] {
+                break (a, b);
+            }
+        };
+        let presses = [rng.range(1..=100), rng.range(1..=100)];
+        let prize = [0, 1].map(|i| presses[0] * a[i] + presses[1] * b[i]);
+        tokens += presses[0] * 3 + presses[1];
+        if machine > 0 {
+            input.push('\n');
+        }
+        let _ = writeln!(input, "Button A: X+{}, Y+{}", a[0], a[1]);
+        let _ = writeln!(input, "Button B: X+{}, Y+{}", b[0], b[1]);
+        let _ = writeln!(input, "Prize: X={}, Y={}", prize[0], prize[1]);
+    }
+    Generated::known(input, Some(tokens.into()), None)
+}
@@ -1,8 +1,10 @@
-pub type Point = [usize; 2];
+use crate::geometry::Vector;
 
-pub fn find_units_along_each_line(line_1: &[Point; 2], line_2: &[Point; 2]) -> Option<[usize; 2]> {
-    let [[x1, y1], [x2, y2]] = to_signed(line_1);
-    let [[x3, y3], [x4, y4]] = to_signed(line_2);
+pub type Line = [Vector; 2];
+
+pub fn find_units_along_each_line(line_1: &Line, line_2: &Line) -> Option<[usize; 2]> {
+    let [Vector { x: x1, y: y1 }, Vector { x: x2, y: y2 }] = *line_1;
+    let [Vector { x: x3, y: y3 }, Vector { x: x4, y: y4 }] = *line_2;
     let denominator = (x1 - x2) * (y3 - y4) - (y1 - y2) * (x3 - x4);
     if denominator == 0 {
         return None;
@@ -20,14 +22,6 @@ pub fn find_units_along_each_line(line_1: &[Point; 2], line_2: &[Point; 2]) -> O
     Some([t as usize, u as usize])
 }
 
-type SignedLine = [[isize; 2]; 2];
-
-fn to_signed(line: &[Point; 2]) -> SignedLine {
-    let [x1, y1] = line[0];
-    let [x2, y2] = line[1];
-    [[x1 as isize, y1 as isize], [x2 as isize, y2 as isize]]
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -35,25 +29,25 @@ mod tests {
     #[test]
     fn can_find_lines_stop_at_same_point() {
         assert_eq!(
-            find_units_along_each_line(&[[0, 0], [1, 1]], &[[2, 0], [2, 1]]),
+            find_units_along_each_line(&line([0, 0], [1, 1]), &line([2, 0], [2, 1])),
             Some([2, 2])
         );
     }
 
     #[test]
     fn can_find_two_a_presses_then_one_b_press() {
-        let a = [1, 1];
-        let b = [0, 1];
-        let prize = [2, 3];
-        let line_1 = [[0, 0], a];
-        let line_2 = [prize, [prize[0] - b[0], prize[1] - b[1]]];
+        let a = Vector::new(1, 1);
+        let b = Vector::new(0, 1);
+        let prize = Vector::new(2, 3);
+        let line_1 = [Vector::default(), a];
+        let line_2 = [prize, prize - b];
         assert_eq!(find_units_along_each_line(&line_1, &line_2), Some([2, 1]));
     }
 
     #[test]
     fn can_find_lines_cross_on_a_point_where_they_do_not_stop() {
         assert_eq!(
-            find_units_along_each_line(&[[0, 0], [2, 2]], &[[0, 2], [2, 0]]),
+            find_units_along_each_line(&line([0, 0], [2, 2]), &line([0, 2], [2, 0])),
             None
         );
     }
@@ -61,7 +55,7 @@ mod tests {
     #[test]
     fn can_find_parallel_lines_never_touch() {
         assert_eq!(
-            find_units_along_each_line(&[[0, 0], [1, 0]], &[[0, 1], [1, 1]]),
+            find_units_along_each_line(&line([0, 0], [1, 0]), &line([0, 1], [1, 1])),
             None
         );
     }
@@ -69,7 +63,7 @@ mod tests {
     #[test]
     fn can_find_lines_meet_with_negative_x() {
         assert_eq!(
-            find_units_along_each_line(&[[0, 1], [1, 2]], &[[0, 0], [1, 0]]),
+            find_units_along_each_line(&line([0, 1], [1, 2]), &line([0, 0], [1, 0])),
             None
         );
     }
@@ -77,7 +71,7 @@ mod tests {
     #[test]
     fn can_find_lines_meet_with_negative_y() {
         assert_eq!(
-            find_units_along_each_line(&[[0, 0], [0, 1]], &[[1, 0], [2, 1]]),
+            find_units_along_each_line(&line([0, 0], [0, 1]), &line([1, 0], [2, 1])),
             None
         );
     }
@@ -85,8 +79,12 @@ mod tests {
     #[test]
     fn can_find_lines_cross_on_a_non_whole_point() {
         assert_eq!(
-            find_units_along_each_line(&[[0, 0], [1, 1]], &[[0, 1], [1, 0]]),
+            find_units_along_each_line(&line([0, 0], [1, 1]), &line([0, 1], [1, 0])),
             None
         );
     }
+
+    fn line([x1, y1]: [isize; 2], [x2, y2]: [isize; 2]) -> Line {
+        [Vector::new(x1, y1), Vector::new(x2, y2)]
+    }
 }
@@ -0,0 +1,32 @@
+use crate::day13::claw_machines::ClawMachines;
+use crate::geometry::Point;
+use crate::solution::Answer;
+
+const MAX_PRESSES: usize = 100;
+
+pub fn intersect_lines(input: &str) -> Option<Answer> {
+    Some(ClawMachines::parse(input).ok()?.sum_min_tokens().into())
+}
+
+pub fn try_all_presses(input: &str) -> Option<Answer> {
+    let machines = ClawMachines::parse(input).ok()?;
+    let tokens: usize = machines
+        .iter()
+        .map(|machine| {
+            let [a, b] = [machine.button_a_vector, machine.button_b_vector];
+            (0..=MAX_PRESSES)
+                .flat_map(|a_presses| {
+                    (0..=MAX_PRESSES).map(move |b_presses| (a_presses, b_presses))
+                })
+                .filter(|(a_presses, b_presses)| {
+                    let x = a.x as usize * a_presses + b.x as usize * b_presses;
+                    let y = a.y as usize * a_presses + b.y as usize * b_presses;
+                    Point::new(x, y) == machine.prize_location
+                })
+                .map(|(a_presses, b_presses)| a_presses * 3 + b_presses)
+                .min()
+                .unwrap_or(0)
+        })
+        .sum();
+    Some(tokens.into())
+}
@@ -1,3 +1,32 @@
+pub mod generator;
 mod robot;
 pub mod robots;
 mod safety_factor;
+
+use crate::day14::robot::FloorSize;
+use crate::day14::robots::Robots;
+use crate::observe;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+const DAY: u8 = 14;
+const FLOOR: FloorSize = [101, 103];
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = DAY;
+    type Input = Robots;
+    type Part1 = usize;
+    type Part2 = Option<usize>;
+
+    fn parse(input: &str) -> Result<Robots, ParseError> {
+        Robots::parse(input)
+    }
+    fn part_1(input: &Robots) -> usize {
+        input.safety_factor_after_seconds(100, FLOOR)
+    }
+    fn part_2(input: &Robots) -> Option<usize> {
+        input.first_time_without_overlaps_observed(FLOOR, observe::current())
+    }
+}
@@ -0,0 +1,19 @@
+use crate::day14::FLOOR;
+use crate::generate::{Generated, Rng};
+use std::fmt::Write;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let [width, height] = FLOOR;
+    let mut input = String::new();
+    for _ in 0..size {
+        let _ = writeln!(
+            input,
+            "p={},{} v={},{}",
+            rng.below(width),
+            rng.below(height),
+            rng.signed(-99..=99),
+            rng.signed(-99..=99)
+        );
+    }
+    Generated::unknown(input)
+}
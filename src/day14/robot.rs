@@ -1,106 +1,90 @@
+use crate::day14::DAY;
+use crate::geometry::{Point, Size, Vector};
+use crate::grid::Grid;
+use crate::parse::{parse_number, ParseError};
 use regex::Regex;
-use std::collections::HashMap;
-use std::str::FromStr;
+use std::collections::{HashMap, HashSet};
 
+#[derive(Debug, Eq, PartialEq)]
 pub struct Robot {
-    position: Position,
-    velocity: Velocity,
+    position: Point,
+    velocity: Vector,
 }
 
-pub type Position = [usize; 2];
-
-pub type Velocity = [isize; 2];
-
-pub type FloorSize = [usize; 2];
-
-pub type SignedFloorSize = [isize; 2];
+pub type FloorSize = Size;
 
 impl Robot {
-    pub fn parse_vec(string: &str) -> Vec<Robot> {
+    pub fn parse_vec(string: &str) -> Result<Vec<Robot>, ParseError> {
         let regex = Regex::new(r"p=([0-9]+),([0-9]+) v=([\-0-9]+),([\-0-9]+)").unwrap();
         string
             .lines()
-            .map(|line| Self::parse(line, &regex))
+            .map(|line| Self::parse(line, &regex).map_err(|e| e.within(string, line)))
             .collect()
     }
-    fn parse(string: &str, regex: &Regex) -> Robot {
-        let captures = regex.captures(string).unwrap();
-        let x = usize::from_str(&captures[1]).unwrap();
-        let y = usize::from_str(&captures[2]).unwrap();
-        let vx = isize::from_str(&captures[3]).unwrap();
-        let vy = isize::from_str(&captures[4]).unwrap();
-        Robot {
-            position: [x, y],
-            velocity: [vx, vy],
-        }
+    fn parse(string: &str, regex: &Regex) -> Result<Robot, ParseError> {
+        let captures = regex
+            .captures(string)
+            .ok_or_else(|| ParseError::at(DAY, string, string, "expected p=x,y v=x,y"))?;
+        let number = |i: usize| captures.get(i).unwrap().as_str();
+        let x = parse_number(DAY, string, number(1))?;
+        let y = parse_number(DAY, string, number(2))?;
+        let vx = parse_number(DAY, string, number(3))?;
+        let vy = parse_number(DAY, string, number(4))?;
+        Ok(Robot {
+            position: Point::new(x, y),
+            velocity: Vector::new(vx, vy),
+        })
+    }
+    pub fn print(&self) -> String {
+        let Point { x, y } = self.position;
+        let Vector { x: vx, y: vy } = self.velocity;
+        format!("p={x},{y} v={vx},{vy}")
     }
     pub fn before_mid(&self, floor: FloorSize, dimension: usize) -> bool {
-        self.position[dimension] < floor[dimension] / 2
+        self.coordinate(dimension) < floor[dimension] / 2
     }
     pub fn after_mid(&self, floor: FloorSize, dimension: usize) -> bool {
-        self.position[dimension] > floor[dimension] / 2
+        self.coordinate(dimension) > floor[dimension] / 2
+    }
+    fn coordinate(&self, dimension: usize) -> usize {
+        [self.position.x, self.position.y][dimension]
     }
 }
 
 pub fn move_for_seconds(robots: &[Robot], floor: FloorSize, seconds: usize) -> Vec<Robot> {
-    let signed_floor = signed_floor_size(floor);
     robots
         .iter()
-        .map(|robot| move_robot_for_seconds(robot, &signed_floor, seconds))
+        .map(|robot| move_robot_for_seconds(robot, floor, seconds))
         .collect()
 }
 
-fn move_robot_for_seconds(robot: &Robot, floor: &SignedFloorSize, seconds: usize) -> Robot {
-    let mut position = [0, 0];
-    for i in 0..2 {
-        position[i] =
-            move_dimension_for_seconds(robot.position[i], robot.velocity[i], floor[i], seconds);
-    }
+fn move_robot_for_seconds(robot: &Robot, floor: FloorSize, seconds: usize) -> Robot {
     Robot {
-        position,
+        position: robot
+            .position
+            .add_wrapping(robot.velocity * seconds as isize, floor),
         velocity: robot.velocity,
     }
 }
 
-fn move_dimension_for_seconds(
-    position: usize,
-    velocity: isize,
-    length: isize,
-    seconds: usize,
-) -> usize {
-    let wrapping = position as isize + seconds as isize * velocity;
-    let remainder = wrapping % length;
-    if remainder >= 0 {
-        remainder as usize
-    } else {
-        (length + remainder) as usize
-    }
+pub fn has_overlaps(robots: &[Robot]) -> bool {
+    let mut positions = HashSet::new();
+    !robots.iter().all(|robot| positions.insert(robot.position))
 }
 
-fn signed_floor_size(floor_size: FloorSize) -> SignedFloorSize {
-    let [width, height] = floor_size;
-    [width as isize, height as isize]
-}
-
-pub fn print_robots(robots: &Vec<Robot>, floor: FloorSize) -> String {
+pub fn render_robots(robots: &Vec<Robot>, floor: FloorSize) -> Grid<char> {
     let position_to_count = index_position_to_count(robots);
-    let mut str = String::new();
-    for y in 0..floor[1] {
-        for x in 0..floor[0] {
-            str.push_str(
-                &position_to_count
-                    .get(&[x, y])
-                    .map(print_count)
-                    .unwrap_or(".".to_string()),
-            );
-        }
-        str.push('\n');
-    }
-    str
+    let [width, height] = floor;
+    Grid::from_fn(width, height, |point| {
+        position_to_count
+            .get(&point)
+            .map(print_count)
+            .unwrap_or('.')
+    })
 }
 
-fn index_position_to_count(robots: &Vec<Robot>) -> HashMap<Position, usize> {
-    let mut position_to_count: HashMap<Position, usize> = HashMap::new();
+fn index_position_to_count(robots: &Vec<Robot>) -> HashMap<Point, usize> {
+    let mut position_to_count: HashMap<Point, usize> = HashMap::new();
     for robot in robots {
         position_to_count
             .entry(robot.position)
@@ -110,12 +94,8 @@ fn index_position_to_count(robots: &Vec<Robot>) -> HashMap<Position, usize> {
     position_to_count
 }
 
-fn print_count(count: &usize) -> String {
-    if *count > 9 {
-        "X".to_string()
-    } else {
-        count.to_string()
-    }
+fn print_count(count: &usize) -> char {
+    char::from_digit(*count as u32, 10).unwrap_or('X')
 }
 
 #[cfg(test)]
@@ -127,17 +107,37 @@ mod tests {
     #[test]
     fn can_parse_robots() {
         let string = input_to_string("day14/example.txt").unwrap();
-        let robots = Robot::parse_vec(&string);
+        let robots = Robot::parse_vec(&string).unwrap();
         let floor = [11, 7];
         assert_snapshot!(print_robots(&robots, floor))
     }
 
+    #[test]
+    fn can_refuse_invalid_velocity() {
+        let error = Robot::parse_vec("p=0,4 v=3,-3\np=6,3 v=-1,--3")
+            .err()
+            .unwrap();
+        assert_eq!([error.line, error.column], [2, 12]);
+        assert_eq!(error.text, "--3")
+    }
+
+    #[test]
+    fn can_refuse_missing_velocity() {
+        let error = Robot::parse_vec("p=0,4 v=3,-3\np=6,3").err().unwrap();
+        assert_eq!([error.line, error.column], [2, 1]);
+        assert_eq!(error.message, "expected p=x,y v=x,y")
+    }
+
     #[test]
     fn can_move_for_100_seconds() {
         let string = input_to_string("day14/example.txt").unwrap();
-        let robots = Robot::parse_vec(&string);
+        let robots = Robot::parse_vec(&string).unwrap();
         let floor = [11, 7];
         let after = move_for_seconds(&robots, floor, 100);
         assert_snapshot!(print_robots(&after, floor))
     }
+
+    fn print_robots(robots: &Vec<Robot>, floor: FloorSize) -> String {
+        render_robots(robots, floor).to_string()
+    }
 }
@@ -1,47 +1,144 @@
-use crate::day14::robot::{move_for_seconds, print_robots, FloorSize, Robot};
+use crate::day14::robot::{has_overlaps, move_for_seconds, render_robots, FloorSize, Robot};
 use crate::day14::safety_factor::safety_factor;
+use crate::day14::FLOOR;
+use crate::grid::Grid;
+use crate::observe::{Observer, Silent};
+use crate::parse::ParseError;
+use crate::render::Snapshot;
+use crate::visualise::{Frame, Frames, Visualise};
 
+const SCAN_TASK: &str = "Scanning for no overlaps";
+
+#[derive(Debug, Eq, PartialEq)]
 pub struct Robots {
     robots: Vec<Robot>,
 }
 
 impl Robots {
-    pub fn parse(string: &str) -> Robots {
-        Robots {
-            robots: Robot::parse_vec(string),
-        }
+    pub fn parse(string: &str) -> Result<Robots, ParseError> {
+        Ok(Robots {
+            robots: Robot::parse_vec(string)?,
+        })
+    }
+    pub fn print(&self) -> String {
+        self.robots
+            .iter()
+            .map(|robot| format!("{}\n", robot.print()))
+            .collect()
     }
     pub fn safety_factor_after_seconds(&self, seconds: usize, floor: FloorSize) -> usize {
         let robots = move_for_seconds(&self.robots, floor, seconds);
         safety_factor(&robots, floor)
     }
-    pub fn print_at_times<T>(
+    pub fn first_time_without_overlaps(&self, floor: FloorSize) -> Option<usize> {
+        self.first_time_without_overlaps_observed(floor, &Silent)
+    }
+    pub fn first_time_without_overlaps_observed(
         &self,
-        times: T,
         floor: FloorSize,
-    ) -> impl Iterator<Item = (usize, String)> + use<'_, T>
-    where
-        T: Iterator<Item = usize>,
-    {
-        times.map(move |time| {
-            (
-                time,
-                print_robots(&move_for_seconds(&self.robots, floor, time), floor),
-            )
-        })
+        observer: &dyn Observer,
+    ) -> Option<usize> {
+        let times = floor[0] * floor[1];
+        observer.started(SCAN_TASK, times);
+        let found = (0..times).find(|time| {
+            observer.progressed(SCAN_TASK, time + 1, times);
+            let robots = move_for_seconds(&self.robots, floor, *time);
+            !has_overlaps(&robots)
+        });
+        if let Some(time) = found {
+            observer.traced(SCAN_TASK, &format!("no overlaps after {time} seconds"));
+        }
+        observer.finished(SCAN_TASK);
+        found
+    }
+    pub fn frames_on_floor(&self, floor: FloorSize) -> Frames<'_> {
+        Box::new((0..floor[0] * floor[1]).map(move |time| {
+            let robots = move_for_seconds(&self.robots, floor, time);
+            Frame::new(format!("Time {time}"), render_robots(&robots, floor))
+        }))
+    }
+}
+
+impl Visualise for Robots {
+    fn frames(&self) -> Frames<'_> {
+        self.frames_on_floor(FLOOR)
+    }
+}
+
+impl Snapshot for Robots {
+    fn snapshot(&self, part: u8) -> Grid<char> {
+        let time = match part {
+            1 => 100,
+            _ => self.first_time_without_overlaps(FLOOR).unwrap_or(0),
+        };
+        render_robots(&move_for_seconds(&self.robots, FLOOR, time), FLOOR)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::day14::robots::Robots;
+    use crate::day14::DAY;
     use crate::input::input_to_string;
+    use crate::observe::tests::Recorder;
+    use crate::property::{assert_round_trips, Shrink};
 
     #[test]
     fn can_find_safety_factor_for_example() {
         let string = input_to_string("day14/example.txt").unwrap();
-        let robots = Robots::parse(&string);
+        let robots = Robots::parse(&string).unwrap();
         let floor = [11, 7];
         assert_eq!(robots.safety_factor_after_seconds(100, floor), 12)
     }
+
+    #[test]
+    fn can_find_first_time_without_overlaps_for_example() {
+        let string = input_to_string("day14/example.txt").unwrap();
+        let robots = Robots::parse(&string).unwrap();
+        let floor = [11, 7];
+        assert_eq!(robots.first_time_without_overlaps(floor), Some(1))
+    }
+
+    #[test]
+    fn can_report_scan_for_no_overlaps() {
+        let robots = Robots::parse("p=0,0 v=1,0\np=1,0 v=0,0").unwrap();
+        let recorder = Recorder::default();
+        assert_eq!(
+            robots.first_time_without_overlaps_observed([3, 1], &recorder),
+            Some(0)
+        );
+        assert_eq!(
+            recorder.events(),
+            [
+                "Scanning for no overlaps started 3",
+                "Scanning for no overlaps 1/3",
+                "Scanning for no overlaps: no overlaps after 0 seconds",
+                "Scanning for no overlaps finished"
+            ]
+        )
+    }
+
+    #[test]
+    fn can_print_robots() {
+        let robots = Robots::parse("p=0,4 v=3,-3\np=6,3 v=-1,-3").unwrap();
+        assert_eq!(robots.print(), "p=0,4 v=3,-3\np=6,3 v=-1,-3\n")
+    }
+
+    #[test]
+    fn can_round_trip_generated_robots() {
+        assert_round_trips(DAY, Shrink::Lines, Robots::parse, Robots::print)
+    }
+
+    #[test]
+    fn can_visualise_robots_each_second() {
+        let robots = Robots::parse("p=0,0 v=1,0\np=2,0 v=-1,0").unwrap();
+        let frames: Vec<String> = robots
+            .frames_on_floor([3, 1])
+            .map(|frame| frame.to_string())
+            .collect();
+        assert_eq!(
+            frames,
+            vec!["Time 0\n1.1\n", "Time 1\n.2.\n", "Time 2\n1.1\n"]
+        )
+    }
 }
@@ -34,7 +34,7 @@ mod tests {
     fn can_find_safety_factor() {
         let string = input_to_string("day14/example.txt").unwrap();
         let floor = [11, 7];
-        let robots = move_for_seconds(&Robot::parse_vec(&string), floor, 100);
+        let robots = move_for_seconds(&Robot::parse_vec(&string).unwrap(), floor, 100);
         assert_eq!(safety_factor(&robots, floor), 12)
     }
 }
@@ -1,4 +1,33 @@
+pub mod generator;
 mod move_robot;
 pub mod robot_plan;
-mod warehouse;
-mod warehouse_box;
+pub mod warehouse;
+pub mod warehouse_box;
+
+use crate::day15::robot_plan::RobotPlan;
+use crate::observe;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+const DAY: u8 = 15;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = DAY;
+    type Input = RobotPlan;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<RobotPlan, ParseError> {
+        RobotPlan::parse(input)
+    }
+    fn part_1(input: &RobotPlan) -> usize {
+        input.sum_gps_coordinates_at_end_observed(observe::current())
+    }
+    fn part_2(input: &RobotPlan) -> usize {
+        input
+            .scale_up()
+            .sum_gps_coordinates_at_end_observed(observe::current())
+    }
+}
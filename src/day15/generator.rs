@@ -0,0 +1,31 @@
+use crate::generate::{Generated, Rng};
+
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.max(3);
+    let robot = [side / 2, side / 2];
+    let mut input = String::with_capacity((side + 1) * side + side * 6);
+    for y in 0..side {
+        for x in 0..side {
+            let edge = x == 0 || y == 0 || x == side - 1 || y == side - 1;
+            input.push(if [x, y] == robot {
+                '@'
+            } else if edge || rng.chance(8) {
+                '#'
+            } else if rng.chance(25) {
+                'O'
+            } else {
+                '.'
+            });
+        }
+        input.push('\n');
+    }
+    input.push('\n');
+    for i in 0..side * 5 {
+        if i > 0 && i % 70 == 0 {
+            input.push('\n');
+        }
+        input.push(rng.pick(&['^', '>', 'v', '<']));
+    }
+    input.push('\n');
+    Generated::unknown(input)
+}
@@ -1,19 +1,14 @@
-use crate::day15::warehouse::{Point, Warehouse};
+use crate::day15::warehouse::Warehouse;
 use crate::day15::warehouse_box::WarehouseBox;
+use crate::geometry::{Direction, Point};
 use std::collections::HashSet;
 
-#[derive(Copy, Clone)]
-pub enum Direction {
-    Up,
-    Right,
-    Left,
-    Down,
-}
-
 pub fn move_robot(warehouse: &Warehouse, direction: Direction) -> Warehouse {
-    let new_position = next_point(warehouse.robot_position(), direction);
     let mut new_warehouse = warehouse.clone();
-    if !warehouse.is_on_map(new_position) || warehouse.is_wall(new_position) {
+    let Some(new_position) = warehouse.next_point(warehouse.robot_position(), direction) else {
+        return new_warehouse;
+    };
+    if warehouse.is_wall(new_position) {
         return new_warehouse;
     }
     match warehouse.box_at(new_position) {
@@ -36,16 +31,19 @@ fn move_boxes(
     let mut boxes = vec![first_box];
     let mut to_move = HashSet::from([first_box.number()]);
     loop {
-        let points: Vec<Point> = boxes
+        let points: Vec<Option<Point>> = boxes
             .iter()
             .flat_map(|b| b.points_in_dir(direction))
-            .map(|p| next_point(p, direction))
+            .map(|p| warehouse.next_point(p, direction))
             .collect();
         boxes = vec![];
         for point in points {
+            let Some(point) = point else {
+                return false;
+            };
             if let Some(b) = warehouse.box_at(point) {
                 boxes.push(b);
-            } else if !warehouse.is_on_map(point) || warehouse.is_wall(point) {
+            } else if warehouse.is_wall(point) {
                 return false;
             }
         }
@@ -59,21 +57,10 @@ fn move_boxes(
     }
 }
 
-fn next_point(point: Point, direction: Direction) -> Point {
-    let [x, y] = point;
-    match direction {
-        Direction::Up => [x, y.wrapping_sub(1)],
-        Direction::Down => [x, y + 1],
-        Direction::Left => [x.wrapping_sub(1), y],
-        Direction::Right => [x + 1, y],
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::day15::move_robot::Direction::{Down, Left, Right, Up};
-    use crate::day15::warehouse::tests::print;
+    use crate::geometry::Direction::{Down, Left, Right, Up};
 
     #[test]
     fn can_move_robot() {
@@ -81,8 +68,8 @@ mod tests {
             ...\n\
             .@.\n\
             ...\n";
-        let before = Warehouse::parse(string);
-        let after = [Up, Down, Left, Right].map(|d| print(&before.move_robot(d)));
+        let before = Warehouse::parse(string).unwrap();
+        let after = [Up, Down, Left, Right].map(|d| before.move_robot(d).print());
         assert_eq!(
             after,
             [
@@ -104,23 +91,23 @@ mod tests {
 
     #[test]
     fn can_push_boxes() {
-        let before = Warehouse::parse("@OO.");
+        let before = Warehouse::parse("@OO.").unwrap();
         let after = before.move_robot(Right);
-        assert_eq!(print(&after), ".@OO\n")
+        assert_eq!(after.print(), ".@OO\n")
     }
 
     #[test]
     fn can_push_big_boxes_right() {
-        let before = Warehouse::parse("@[][].");
+        let before = Warehouse::parse("@[][].").unwrap();
         let after = before.move_robot(Right);
-        assert_eq!(print(&after), ".@[][]\n")
+        assert_eq!(after.print(), ".@[][]\n")
     }
 
     #[test]
     fn can_push_big_boxes_left() {
-        let before = Warehouse::parse(".[][]@");
+        let before = Warehouse::parse(".[][]@").unwrap();
         let after = before.move_robot(Left);
-        assert_eq!(print(&after), "[][]@.\n")
+        assert_eq!(after.print(), "[][]@.\n")
     }
 
     #[test]
@@ -130,14 +117,14 @@ mod tests {
             .[].\n\
             []..\n\
             ..[]";
-        let before = Warehouse::parse(string);
+        let before = Warehouse::parse(string).unwrap();
         let after = before.move_robot(Down);
         let expected = "\
             ....\n\
             .@..\n\
             .[].\n\
             [][]\n";
-        assert_eq!(print(&after), expected)
+        assert_eq!(after.print(), expected)
     }
 
     #[test]
@@ -148,7 +135,7 @@ mod tests {
             .[].\n\
             ..[]\n\
             ....";
-        let before = Warehouse::parse(string);
+        let before = Warehouse::parse(string).unwrap();
         let after = before.move_robot(Down);
         let expected = "\
             ....\n\
@@ -156,34 +143,34 @@ mod tests {
             []..\n\
             .[].\n\
             ..[]\n";
-        assert_eq!(print(&after), expected)
+        assert_eq!(after.print(), expected)
     }
 
     #[test]
     fn can_stop_at_wall() {
-        let before = Warehouse::parse("@#");
+        let before = Warehouse::parse("@#").unwrap();
         let after = before.move_robot(Right);
-        assert_eq!(print(&after), "@#\n")
+        assert_eq!(after.print(), "@#\n")
     }
 
     #[test]
     fn can_stop_at_edge() {
-        let before = Warehouse::parse("@");
+        let before = Warehouse::parse("@").unwrap();
         let after = before.move_robot(Right);
-        assert_eq!(print(&after), "@\n")
+        assert_eq!(after.print(), "@\n")
     }
 
     #[test]
     fn can_stop_pushing_at_wall() {
-        let before = Warehouse::parse("@O#");
+        let before = Warehouse::parse("@O#").unwrap();
         let after = before.move_robot(Right);
-        assert_eq!(print(&after), "@O#\n")
+        assert_eq!(after.print(), "@O#\n")
     }
 
     #[test]
     fn can_stop_pushing_at_edge() {
-        let before = Warehouse::parse("@O");
+        let before = Warehouse::parse("@O").unwrap();
         let after = before.move_robot(Right);
-        assert_eq!(print(&after), "@O\n")
+        assert_eq!(after.print(), "@O\n")
     }
 }
@@ -1,6 +1,14 @@
-use crate::day15::move_robot::Direction;
-use crate::day15::move_robot::Direction::{Down, Left, Right, Up};
 use crate::day15::warehouse::Warehouse;
+use crate::day15::DAY;
+use crate::geometry::Direction;
+use crate::grid::Grid;
+use crate::observe::{Observer, Silent};
+use crate::parse::ParseError;
+use crate::render::Snapshot;
+use crate::visualise::{Frame, Frames, Visualise};
+use std::iter;
+
+const FOLLOW_TASK: &str = "Following plan";
 
 pub struct RobotPlan {
     warehouse: Warehouse,
@@ -8,17 +16,23 @@ pub struct RobotPlan {
 }
 
 impl RobotPlan {
-    pub fn parse(string: &str) -> RobotPlan {
-        let parts: Vec<&str> = string.split("\n\n").collect();
-        let warehouse = Warehouse::parse(parts[0]);
-        let directions = parse_directions(parts[1]);
-        RobotPlan {
+    pub fn parse(string: &str) -> Result<RobotPlan, ParseError> {
+        let (warehouse_str, directions_str) = string.split_once("\n\n").ok_or_else(|| {
+            let end = &string[string.len()..];
+            ParseError::at(DAY, string, end, "expected a blank line before the moves")
+        })?;
+        let warehouse = Warehouse::parse(warehouse_str)?;
+        let directions = parse_directions(string, directions_str)?;
+        Ok(RobotPlan {
             warehouse,
             directions,
-        }
+        })
     }
     pub fn sum_gps_coordinates_at_end(&self) -> usize {
-        self.follow().sum_gps_coordinates()
+        self.sum_gps_coordinates_at_end_observed(&Silent)
+    }
+    pub fn sum_gps_coordinates_at_end_observed(&self, observer: &dyn Observer) -> usize {
+        self.follow_observed(observer).sum_gps_coordinates()
     }
     pub fn scale_up(&self) -> RobotPlan {
         RobotPlan {
@@ -27,72 +41,128 @@ impl RobotPlan {
         }
     }
     fn follow(&self) -> Warehouse {
+        self.follow_observed(&Silent)
+    }
+    fn follow_observed(&self, observer: &dyn Observer) -> Warehouse {
         let mut warehouse = self.warehouse.clone();
-        for direction in self.directions.iter() {
+        observer.started(FOLLOW_TASK, self.directions.len());
+        for (number, direction) in self.directions.iter().enumerate() {
             warehouse = warehouse.move_robot(*direction);
+            observer.progressed(FOLLOW_TASK, number + 1, self.directions.len());
         }
+        observer.finished(FOLLOW_TASK);
         warehouse
     }
 }
 
-fn parse_directions(string: &str) -> Vec<Direction> {
-    string
-        .lines()
-        .flat_map(|line| line.chars())
-        .flat_map(to_direction)
-        .collect()
+impl Visualise for RobotPlan {
+    fn frames(&self) -> Frames<'_> {
+        let start = Frame::new("Start", self.warehouse.render());
+        let moves = self.directions.iter().enumerate().scan(
+            self.warehouse.clone(),
+            |warehouse, (number, direction)| {
+                *warehouse = warehouse.move_robot(*direction);
+                let caption = format!("Move {}: {}", number + 1, direction.arrow());
+                Some(Frame::new(caption, warehouse.render()))
+            },
+        );
+        Box::new(iter::once(start).chain(moves))
+    }
 }
 
-fn to_direction(c: char) -> Option<Direction> {
-    match c {
-        '^' => Some(Up),
-        '>' => Some(Right),
-        'v' => Some(Down),
-        '<' => Some(Left),
-        _ => None,
+impl Snapshot for RobotPlan {
+    fn snapshot(&self, part: u8) -> Grid<char> {
+        match part {
+            1 => self.follow().render(),
+            _ => self.scale_up().follow().render(),
+        }
     }
 }
 
+fn parse_directions(input: &str, string: &str) -> Result<Vec<Direction>, ParseError> {
+    string
+        .lines()
+        .flat_map(|line| line.char_indices().map(move |(i, c)| (&line[i..], c)))
+        .map(|(at, c)| {
+            Direction::from_arrow(c).ok_or_else(|| {
+                let text = &at[..c.len_utf8()];
+                ParseError::at(DAY, input, text, "expected one of ^ > v <")
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::day15::warehouse;
     use crate::input::input_to_string;
+    use crate::observe::tests::Recorder;
     use insta::assert_snapshot;
 
     #[test]
     fn can_find_start() {
         let string = input_to_string("day15/small-example.txt").unwrap();
-        let plan = RobotPlan::parse(&string);
-        assert_snapshot!(print_at_start(&plan))
+        let plan = RobotPlan::parse(&string).unwrap();
+        assert_snapshot!(plan.warehouse.print())
     }
 
     #[test]
     fn can_follow_plan() {
         let string = input_to_string("day15/small-example.txt").unwrap();
-        let plan = RobotPlan::parse(&string);
-        assert_snapshot!(print_warehouse(&plan.follow()))
+        let plan = RobotPlan::parse(&string).unwrap();
+        assert_snapshot!(plan.follow().print())
     }
 
     #[test]
     fn can_follow_larger_plan() {
         let string = input_to_string("day15/example.txt").unwrap();
-        let plan = RobotPlan::parse(&string);
-        assert_snapshot!(print_warehouse(&plan.follow()))
+        let plan = RobotPlan::parse(&string).unwrap();
+        assert_snapshot!(plan.follow().print())
     }
 
     #[test]
     fn can_follow_scaled_up_larger_plan() {
         let string = input_to_string("day15/example.txt").unwrap();
-        let plan = RobotPlan::parse(&string);
-        assert_snapshot!(print_warehouse(&plan.scale_up().follow()))
+        let plan = RobotPlan::parse(&string).unwrap();
+        assert_snapshot!(plan.scale_up().follow().print())
     }
 
-    fn print_at_start(plan: &RobotPlan) -> String {
-        warehouse::tests::print(&plan.warehouse)
+    #[test]
+    fn can_report_each_move() {
+        let plan = RobotPlan::parse("#@O.#\n\n>>").unwrap();
+        let recorder = Recorder::default();
+        assert_eq!(plan.sum_gps_coordinates_at_end_observed(&recorder), 3);
+        assert_eq!(
+            recorder.events(),
+            [
+                "Following plan started 2",
+                "Following plan 1/2",
+                "Following plan 2/2",
+                "Following plan finished"
+            ]
+        )
     }
 
-    fn print_warehouse(warehouse: &Warehouse) -> String {
-        warehouse::tests::print(warehouse)
+    #[test]
+    fn can_refuse_unknown_move() {
+        let error = RobotPlan::parse("#@.#\n\n<>\n^x").err().unwrap();
+        assert_eq!([error.line, error.column], [4, 2]);
+        assert_eq!(error.text, "x")
+    }
+
+    #[test]
+    fn can_refuse_missing_moves() {
+        let error = RobotPlan::parse("#@.#\n").err().unwrap();
+        assert_eq!(error.message, "expected a blank line before the moves")
+    }
+
+    #[test]
+    fn can_visualise_each_move() {
+        let plan = RobotPlan::parse("#@O.#\n\n>>").unwrap();
+        let frames: Vec<String> = plan.frames().map(|frame| frame.to_string()).collect();
+        assert_eq!(
+            frames,
+            vec!["Start\n#@O.#\n", "Move 1: >\n#.@O#\n", "Move 2: >\n#.@O#\n"]
+        )
     }
 }
@@ -1,5 +1,9 @@
-use crate::day15::move_robot::{move_robot, Direction};
+use crate::day15::move_robot::move_robot;
 use crate::day15::warehouse_box::WarehouseBox;
+use crate::day15::DAY;
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::parse::ParseError;
 use std::collections::{HashMap, HashSet};
 
 pub const WALL: char = '#';
@@ -8,33 +12,29 @@ pub const BOX_LEFT: char = '[';
 pub const BOX_RIGHT: char = ']';
 pub const ROBOT: char = '@';
 
-pub type Point = [usize; 2];
-
-#[derive(Clone)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Warehouse {
-    width: usize,
-    height: usize,
     robot_position: Point,
     boxes: Vec<WarehouseBox>,
     position_to_box_num: HashMap<Point, usize>,
-    walls: HashSet<Point>,
+    walls: Grid<bool>,
 }
 
 impl Warehouse {
-    pub fn parse(string: &str) -> Warehouse {
-        let map: Vec<Vec<char>> = string.lines().map(|line| line.chars().collect()).collect();
-        let width = map.first().map(|line| line.len()).unwrap_or(0);
-        let height = map.len();
-        let boxes = find_boxes(&map, width, height);
+    pub fn parse(string: &str) -> Result<Warehouse, ParseError> {
+        let map = Grid::parse_with(DAY, string, "expected one of # O [ ] @ .", |tile| {
+            [WALL, BOX, BOX_LEFT, BOX_RIGHT, ROBOT, '.']
+                .contains(&tile)
+                .then_some(tile)
+        })?;
+        let boxes = find_boxes(&map);
         let position_to_box_num = index_point_to_box_number(&boxes);
-        Warehouse {
-            width,
-            height,
-            walls: map_walls(&map, width, height),
-            robot_position: find_robot(&map, width, height),
+        Ok(Warehouse {
+            walls: map.map(|tile| *tile == WALL),
+            robot_position: find_robot(&map),
             boxes,
             position_to_box_num,
-        }
+        })
     }
     pub fn move_robot(&self, direction: Direction) -> Warehouse {
         move_robot(self, direction)
@@ -53,8 +53,6 @@ impl Warehouse {
         let boxes = scale_up_boxes(&self.boxes);
         let position_to_box_num = index_point_to_box_number(&boxes);
         Warehouse {
-            width: self.width * 2,
-            height: self.height,
             walls: scale_up_walls(&self.walls),
             robot_position: scale_up_robot(self.robot_position),
             boxes,
@@ -67,11 +65,26 @@ impl Warehouse {
             .map(|number| &self.boxes[*number])
     }
     pub fn is_wall(&self, point: Point) -> bool {
-        self.walls.contains(&point)
+        self.walls[point]
+    }
+    pub fn next_point(&self, point: Point, direction: Direction) -> Option<Point> {
+        self.walls.step(point, direction)
+    }
+    pub fn render(&self) -> Grid<char> {
+        Grid::from_fn(self.walls.width(), self.walls.height(), |point| {
+            if point == self.robot_position {
+                ROBOT
+            } else if self.is_wall(point) {
+                WALL
+            } else if let Some(b) = self.box_at(point) {
+                b.tile_at(point)
+            } else {
+                '.'
+            }
+        })
     }
-    pub fn is_on_map(&self, point: Point) -> bool {
-        let [x, y] = point;
-        x < self.width && y < self.height
+    pub fn print(&self) -> String {
+        self.render().to_string()
     }
     pub fn set_robot(&mut self, position: Point) {
         self.robot_position = position;
@@ -92,18 +105,20 @@ impl Warehouse {
     }
 }
 
-fn find_robot(tiles: &[Vec<char>], width: usize, height: usize) -> Point {
-    (0..height)
-        .flat_map(|y| (0..width).map(move |x| [x, y]))
-        .find(|[x, y]| tiles[*y][*x] == ROBOT)
-        .unwrap_or([width, height])
+fn find_robot(tiles: &Grid<char>) -> Point {
+    tiles
+        .iter()
+        .find(|(_, tile)| **tile == ROBOT)
+        .map(|(point, _)| point)
+        .unwrap_or(Point::new(tiles.width(), tiles.height()))
 }
 
-fn find_boxes(tiles: &[Vec<char>], width: usize, height: usize) -> Vec<WarehouseBox> {
-    position_tiles(tiles, width, height)
+fn find_boxes(tiles: &Grid<char>) -> Vec<WarehouseBox> {
+    tiles
+        .iter()
         .filter(|(_, tile)| [BOX, BOX_LEFT].contains(tile))
         .enumerate()
-        .map(|(number, (point, tile))| WarehouseBox::from_tile_at_point(number, tile, point))
+        .map(|(number, (point, tile))| WarehouseBox::from_tile_at_point(number, *tile, point))
         .collect()
 }
 
@@ -114,34 +129,17 @@ fn index_point_to_box_number(boxes: &[WarehouseBox]) -> HashMap<Point, usize> {
         .collect()
 }
 
-fn map_walls(tiles: &[Vec<char>], width: usize, height: usize) -> HashSet<Point> {
-    position_tiles(tiles, width, height)
-        .filter(|(_, tile)| WALL == *tile)
-        .map(|(point, _)| point)
-        .collect()
-}
-
-fn position_tiles(
-    tiles: &[Vec<char>],
-    width: usize,
-    height: usize,
-) -> impl Iterator<Item = (Point, char)> + use<'_> {
-    positions(width, height).map(|[x, y]| ([x, y], tiles[y][x]))
-}
-
-fn positions(width: usize, height: usize) -> impl Iterator<Item = Point> {
-    (0..height).flat_map(move |y| (0..width).map(move |x| [x, y]))
-}
-
 fn gps_coordinate(point: Point) -> usize {
-    point[1] * 100 + point[0]
+    point.y * 100 + point.x
 }
 
-fn scale_up_walls(walls: &HashSet<Point>) -> HashSet<Point> {
-    walls
-        .iter()
-        .flat_map(|[x, y]| [[*x * 2, *y], [*x * 2 + 1, *y]])
-        .collect()
+fn scale_up_walls(walls: &Grid<bool>) -> Grid<bool> {
+    let mut scaled = Grid::filled(walls.width() * 2, walls.height(), false);
+    for (Point { x, y }, wall) in walls.iter() {
+        scaled[Point::new(x * 2, y)] = *wall;
+        scaled[Point::new(x * 2 + 1, y)] = *wall;
+    }
+    scaled
 }
 
 fn scale_up_boxes(boxes: &[WarehouseBox]) -> Vec<WarehouseBox> {
@@ -149,14 +147,13 @@ fn scale_up_boxes(boxes: &[WarehouseBox]) -> Vec<WarehouseBox> {
 }
 
 fn scale_up_robot(position: Point) -> Point {
-    let [x, y] = position;
-    [x * 2, y]
+    Point::new(position.x * 2, position.y)
 }
 
 #[cfg(test)]
-pub mod tests {
+mod tests {
     use super::*;
-    use crate::day15::warehouse_box;
+    use crate::property::{assert_round_trips, Shrink};
     use insta::assert_snapshot;
 
     #[test]
@@ -170,14 +167,27 @@ pub mod tests {
             #...O..#\n\
             #......#\n\
             ########\n";
-        let warehouse = Warehouse::parse(string);
-        assert_eq!(print(&warehouse), string);
-        assert_eq!(warehouse.robot_position, [2, 2]);
+        let warehouse = Warehouse::parse(string).unwrap();
+        assert_eq!(warehouse.print(), string);
+        assert_eq!(warehouse.robot_position, Point::new(2, 2));
+    }
+
+    #[test]
+    fn can_round_trip_generated_warehouses() {
+        let parse = |string: &str| Warehouse::parse(string.split("\n\n").next().unwrap_or(""));
+        assert_round_trips(DAY, Shrink::Lines, parse, Warehouse::print)
+    }
+
+    #[test]
+    fn can_refuse_unknown_tile() {
+        let error = Warehouse::parse("####\n#@x#\n####").err().unwrap();
+        assert_eq!([error.line, error.column], [2, 3]);
+        assert_eq!(error.text, "x")
     }
 
     #[test]
     fn can_find_gps_coordinate() {
-        assert_eq!(gps_coordinate([4, 1]), 104)
+        assert_eq!(gps_coordinate(Point::new(4, 1)), 104)
     }
 
     #[test]
@@ -186,7 +196,7 @@ pub mod tests {
             #######\n\
             #...O..\n\
             #..@.O.\n";
-        let warehouse = Warehouse::parse(string);
+        let warehouse = Warehouse::parse(string).unwrap();
         assert_eq!(warehouse.sum_gps_coordinates(), 309)
     }
 
@@ -201,8 +211,8 @@ pub mod tests {
             #...O..#\n\
             #......#\n\
             ########\n";
-        let warehouse = Warehouse::parse(string);
-        assert_snapshot!(print(&warehouse.scale_up()))
+        let warehouse = Warehouse::parse(string).unwrap();
+        assert_snapshot!(warehouse.scale_up().print())
     }
 
     #[test]
@@ -211,30 +221,7 @@ pub mod tests {
             #######\n\
             #...[].\n\
             #..@.[]\n";
-        let warehouse = Warehouse::parse(string);
+        let warehouse = Warehouse::parse(string).unwrap();
         assert_eq!(warehouse.sum_gps_coordinates(), 309)
     }
-
-    pub fn print(warehouse: &Warehouse) -> String {
-        let mut string = "".to_string();
-        for y in 0..warehouse.height {
-            for x in 0..warehouse.width {
-                string.push(contents(warehouse, [x, y]));
-            }
-            string.push('\n');
-        }
-        string
-    }
-
-    fn contents(warehouse: &Warehouse, point: Point) -> char {
-        if point == warehouse.robot_position() {
-            ROBOT
-        } else if warehouse.is_wall(point) {
-            WALL
-        } else if let Some(b) = warehouse.box_at(point) {
-            warehouse_box::tests::char_at(b, point)
-        } else {
-            '.'
-        }
-    }
 }
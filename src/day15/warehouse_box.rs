@@ -1,7 +1,7 @@
-use crate::day15::move_robot::Direction;
-use crate::day15::warehouse::{Point, BOX_LEFT, BOX_RIGHT};
+use crate::day15::warehouse::{BOX, BOX_LEFT, BOX_RIGHT};
+use crate::geometry::{Direction, Point};
 
-#[derive(Clone)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct WarehouseBox {
     number: usize,
     position: Point,
@@ -10,7 +10,6 @@ pub struct WarehouseBox {
 
 impl WarehouseBox {
     pub fn from_tile_at_point(number: usize, tile: char, point: Point) -> WarehouseBox {
-        let [x, y] = point;
         match tile {
             BOX_LEFT => WarehouseBox {
                 number,
@@ -19,7 +18,7 @@ impl WarehouseBox {
             },
             BOX_RIGHT => WarehouseBox {
                 number,
-                position: [x - 1, y],
+                position: Point::new(point.x - 1, point.y),
                 width: 2,
             },
             _ => WarehouseBox {
@@ -30,16 +29,16 @@ impl WarehouseBox {
         }
     }
     pub fn points_in_dir(&self, direction: Direction) -> Vec<Point> {
-        let [x, y] = self.position;
+        let Point { x, y } = self.position;
         match direction {
-            Direction::Left => vec![[x, y]],
-            Direction::Right => vec![[x + self.width - 1, y]],
-            _ => (0..self.width).map(move |i| [x + i, y]).collect(),
+            Direction::Left => vec![self.position],
+            Direction::Right => vec![Point::new(x + self.width - 1, y)],
+            _ => self.points_iter().collect(),
         }
     }
     pub fn points_iter(&self) -> impl Iterator<Item = Point> {
-        let [x, y] = self.position;
-        (0..self.width).map(move |i| [x + i, y])
+        let Point { x, y } = self.position;
+        (0..self.width).map(move |i| Point::new(x + i, y))
     }
     pub fn number(&self) -> usize {
         self.number
@@ -48,42 +47,30 @@ impl WarehouseBox {
         self.position
     }
     pub fn scale_up(&self) -> WarehouseBox {
-        let [x, y] = self.position;
+        let Point { x, y } = self.position;
         WarehouseBox {
             number: self.number,
-            position: [x * 2, y],
+            position: Point::new(x * 2, y),
             width: self.width * 2,
         }
     }
+    pub fn tile_at(&self, point: Point) -> char {
+        if self.width == 1 {
+            BOX
+        } else if point.x == self.position.x {
+            BOX_LEFT
+        } else {
+            BOX_RIGHT
+        }
+    }
     pub fn move_dir(&self, direction: Direction) -> WarehouseBox {
-        let [x, y] = self.position;
-        let position = match direction {
-            Direction::Up => [x, y - 1],
-            Direction::Down => [x, y + 1],
-            Direction::Left => [x - 1, y],
-            Direction::Right => [x + 1, y],
-        };
         WarehouseBox {
             number: self.number,
-            position,
+            position: self
+                .position
+                .step(direction)
+                .expect("box should stay within the warehouse"),
             width: self.width,
         }
     }
 }
-
-#[cfg(test)]
-pub mod tests {
-    use super::*;
-
-    pub fn char_at(b: &WarehouseBox, point: Point) -> char {
-        if b.width == 1 {
-            return 'O';
-        }
-        let offset = point[0] - b.position[0];
-        if offset == 0 {
-            '['
-        } else {
-            ']'
-        }
-    }
-}
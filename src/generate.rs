@@ -0,0 +1,145 @@
+use crate::solution::Answer;
+use std::ops::RangeInclusive;
+
+pub type Generator = fn(&mut Rng, usize) -> Generated;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Generated {
+    pub input: String,
+    pub answers: [Option<Answer>; 2],
+}
+
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+pub fn generator_for_day(day: u8) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => crate::day01::generator::generate,
+        2 => crate::day02::generator::generate,
+        3 => crate::day03::generator::generate,
+        4 => crate::day04::generator::generate,
+        5 => crate::day05::generator::generate,
+        6 => crate::day06::generator::generate,
+        7 => crate::day07::generator::generate,
+        8 => crate::day08::generator::generate,
+        9 => crate::day09::generator::generate,
+        10 => crate::day10::generator::generate,
+        11 => crate::day11::generator::generate,
+        12 => crate::day12::generator::generate,
+        13 => crate::day13::generator::generate,
+        14 => crate::day14::generator::generate,
+        15 => crate::day15::generator::generate,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<Generated> {
+    let generator = generator_for_day(day)?;
+    Some(generator(&mut Rng::new(seed), size))
+}
+
+impl Generated {
+    pub fn unknown(input: String) -> Generated {
+        Generated {
+            input,
+            answers: [None, None],
+        }
+    }
+
+    pub fn known(input: String, part_1: Option<Answer>, part_2: Option<Answer>) -> Generated {
+        Generated {
+            input,
+            answers: [part_1, part_2],
+        }
+    }
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        range.start() + self.below(range.end() - range.start() + 1)
+    }
+
+    pub fn signed(&mut self, range: RangeInclusive<isize>) -> isize {
+        let width = (range.end() - range.start()) as usize + 1;
+        range.start() + self.below(width) as isize
+    }
+
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::solver_for_day;
+
+    #[test]
+    fn can_repeat_sequence_from_seed() {
+        let sequence = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.below(1000)).collect::<Vec<usize>>()
+        };
+        assert_eq!(sequence(7), sequence(7));
+        assert_ne!(sequence(7), sequence(8))
+    }
+
+    #[test]
+    fn can_keep_within_range() {
+        let mut rng = Rng::new(1);
+        assert!((0..1000).all(|_| (3..=5).contains(&rng.range(3..=5))));
+        assert!((0..1000).all(|_| (-2..=2).contains(&rng.signed(-2..=2))))
+    }
+
+    #[test]
+    fn can_generate_parsable_input_for_every_day() {
+        for day in 1..=15 {
+            for seed in 0..3 {
+                let generated = generate(day, seed, 12).unwrap();
+                let parsed = solver_for_day(day)
+                    .unwrap()
+                    .parse(&generated.input)
+                    .unwrap_or_else(|e| panic!("day {day} seed {seed}: {e}"));
+                for (part, known) in [1, 2].into_iter().zip(generated.answers) {
+                    if let Some(known) = known {
+                        assert_eq!(parsed.part(part), Some(known), "day {day} part {part}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn can_refuse_day_without_generator() {
+        assert!(generate(25, 0, 10).is_none())
+    }
+}
@@ -0,0 +1,260 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+#[derive(Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+pub struct Vector {
+    pub x: isize,
+    pub y: isize,
+}
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+pub type Size = [usize; 2];
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Point {
+        Point { x, y }
+    }
+
+    pub fn checked_add(self, vector: Vector) -> Option<Point> {
+        Some(Point {
+            x: self.x.checked_add_signed(vector.x)?,
+            y: self.y.checked_add_signed(vector.y)?,
+        })
+    }
+
+    pub fn add_within(self, vector: Vector, size: Size) -> Option<Point> {
+        self.checked_add(vector)
+            .filter(|point| point.is_within(size))
+    }
+
+    pub fn add_wrapping(self, vector: Vector, size: Size) -> Point {
+        let [width, height] = size.map(|length| length as isize);
+        Point {
+            x: (self.x as isize + vector.x).rem_euclid(width) as usize,
+            y: (self.y as isize + vector.y).rem_euclid(height) as usize,
+        }
+    }
+
+    pub fn step(self, direction: Direction) -> Option<Point> {
+        self.checked_add(direction.vector())
+    }
+
+    pub fn is_within(self, size: Size) -> bool {
+        let [width, height] = size;
+        self.x < width && self.y < height
+    }
+
+    pub fn to_vector(self) -> Vector {
+        Vector::new(self.x as isize, self.y as isize)
+    }
+}
+
+impl Vector {
+    pub const EIGHT_WAY: [Vector; 8] = [
+        Vector::new(0, -1),
+        Vector::new(1, -1),
+        Vector::new(1, 0),
+        Vector::new(1, 1),
+        Vector::new(0, 1),
+        Vector::new(-1, 1),
+        Vector::new(-1, 0),
+        Vector::new(-1, -1),
+    ];
+
+    pub const fn new(x: isize, y: isize) -> Vector {
+        Vector { x, y }
+    }
+
+    pub fn rotate_right(self) -> Vector {
+        Vector::new(-self.y, self.x)
+    }
+
+    pub fn rotate_left(self) -> Vector {
+        Vector::new(self.y, -self.x)
+    }
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(0, -1),
+            Direction::Right => Vector::new(1, 0),
+            Direction::Down => Vector::new(0, 1),
+            Direction::Left => Vector::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    pub fn from_arrow(arrow: char) -> Option<Direction> {
+        match arrow {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        self.to_vector() - other.to_vector()
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: isize) -> Vector {
+        Vector::new(self.x * factor, self.y * factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_refuse_step_below_zero() {
+        assert_eq!(
+            [
+                Point::new(0, 3).step(Direction::Left),
+                Point::new(0, 3).step(Direction::Up)
+            ],
+            [None, Some(Point::new(0, 2))]
+        )
+    }
+
+    #[test]
+    fn can_add_within_bounds() {
+        let point = Point::new(2, 2);
+        assert_eq!(
+            [
+                point.add_within(Vector::new(1, 0), [3, 3]),
+                point.add_within(Vector::new(-2, -1), [3, 3])
+            ],
+            [None, Some(Point::new(0, 1))]
+        )
+    }
+
+    #[test]
+    fn can_add_wrapping_around_bounds() {
+        let point = Point::new(2, 4);
+        assert_eq!(
+            point.add_wrapping(Vector::new(2, -3) * 5, [11, 7]),
+            Point::new(1, 3)
+        )
+    }
+
+    #[test]
+    fn can_find_vector_between_points() {
+        assert_eq!(Point::new(1, 5) - Point::new(3, 2), Vector::new(-2, 3))
+    }
+
+    #[test]
+    fn can_rotate_vector() {
+        let up = Direction::Up.vector();
+        assert_eq!(
+            [up.rotate_right(), up.rotate_left(), -up],
+            [
+                Direction::Right.vector(),
+                Direction::Left.vector(),
+                Direction::Down.vector()
+            ]
+        )
+    }
+
+    #[test]
+    fn can_turn_direction() {
+        assert_eq!(
+            Direction::ALL.map(|direction| [
+                direction.turn_right(),
+                direction.turn_left(),
+                direction.opposite()
+            ]),
+            [
+                [Direction::Right, Direction::Left, Direction::Down],
+                [Direction::Down, Direction::Up, Direction::Left],
+                [Direction::Left, Direction::Right, Direction::Up],
+                [Direction::Up, Direction::Down, Direction::Right]
+            ]
+        )
+    }
+
+    #[test]
+    fn can_read_arrows() {
+        let arrows: String = Direction::ALL.map(Direction::arrow).iter().collect();
+        assert_eq!(arrows, "^>v<");
+        assert_eq!(Direction::from_arrow('x'), None)
+    }
+}
@@ -0,0 +1,317 @@
+use crate::geometry::{Direction, Point, Size, Vector};
+use crate::parse::{grid_size, ParseError};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+pub type Line = Vec<Point>;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    pub fn parse(day: u8, input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse_with(day, input, "", Some)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "cells must fill the grid");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    pub fn from_fn<F>(width: usize, height: usize, cell: F) -> Grid<T>
+    where
+        F: Fn(Point) -> T,
+    {
+        let points = (0..height).flat_map(|y| (0..width).map(move |x| Point::new(x, y)));
+        Grid::new(width, height, points.map(cell).collect())
+    }
+
+    pub fn parse_with<F>(
+        day: u8,
+        input: &str,
+        expected: &str,
+        cell: F,
+    ) -> Result<Grid<T>, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let [width, height] = grid_size(day, input)?;
+        let mut cells = Vec::with_capacity(width * height);
+        for line in input.lines() {
+            for (x, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    let text = &line[x..x + c.len_utf8()];
+                    ParseError::at(day, input, text, expected)
+                })?;
+                cells.push(value);
+            }
+        }
+        Ok(Grid::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn size(&self) -> Size {
+        [self.width, self.height]
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.is_within(self.size())
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let [width, height] = self.size();
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    pub fn offset(&self, point: Point, vector: Vector) -> Option<Point> {
+        point.add_within(vector, self.size())
+    }
+
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        self.offset(point, direction.vector())
+    }
+
+    pub fn neighbours_4(&self, point: Point) -> impl Iterator<Item = Point> + use<'_, T> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    pub fn neighbours_8(&self, point: Point) -> impl Iterator<Item = Point> + use<'_, T> {
+        Vector::EIGHT_WAY
+            .into_iter()
+            .filter_map(move |vector| self.offset(point, vector))
+    }
+
+    pub fn rows(&self) -> Vec<Line> {
+        (0..self.height)
+            .map(|y| (0..self.width).map(|x| Point::new(x, y)).collect())
+            .collect()
+    }
+
+    pub fn columns(&self) -> Vec<Line> {
+        (0..self.width)
+            .map(|x| (0..self.height).map(|y| Point::new(x, y)).collect())
+            .collect()
+    }
+
+    pub fn diagonals_down_right(&self) -> Vec<Line> {
+        let [width, height] = [self.width as isize, self.height as isize];
+        let lines = (1 - height..width).map(|diff| {
+            (0..height)
+                .map(|y| [y + diff, y])
+                .filter(|[x, _]| (0..width).contains(x))
+                .map(|[x, y]| Point::new(x as usize, y as usize))
+                .collect()
+        });
+        lines.filter(|line: &Line| !line.is_empty()).collect()
+    }
+
+    pub fn diagonals_up_right(&self) -> Vec<Line> {
+        let [width, height] = [self.width as isize, self.height as isize];
+        let lines = (0..width + height - 1).map(|sum| {
+            (0..width)
+                .map(|x| [x, sum - x])
+                .filter(|[_, y]| (0..height).contains(y))
+                .map(|[x, y]| Point::new(x as usize, y as usize))
+                .collect()
+        });
+        lines.filter(|line: &Line| !line.is_empty()).collect()
+    }
+
+    pub fn render<F>(&self, cell: F) -> String
+    where
+        F: Fn(Point, &T) -> char,
+    {
+        let mut string = String::with_capacity((self.width + 1) * self.height);
+        for (point, value) in self.iter() {
+            string.push(cell(point, value));
+            if point.x + 1 == self.width {
+                string.push('\n');
+            }
+        }
+        string
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point).then(|| point.y * self.width + point.x)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (point, value) in self.iter() {
+            write!(f, "{value}")?;
+            if point.x + 1 == self.width {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_and_print_grid() {
+        let grid = Grid::parse(4, "ABC\nDEF\n").unwrap();
+        assert_eq!(
+            [grid.width(), grid.height(), grid[Point::new(2, 1)] as usize],
+            [3, 2, 'F' as usize]
+        );
+        assert_eq!(grid.to_string(), "ABC\nDEF\n")
+    }
+
+    #[test]
+    fn can_parse_typed_cells() {
+        let error =
+            Grid::parse_with(10, "01\n.9", "expected a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!([error.line, error.column], [2, 1]);
+        assert_eq!(error.text, ".")
+    }
+
+    #[test]
+    fn can_refuse_ragged_grid() {
+        let error = Grid::parse(4, "ABC\nDE").unwrap_err();
+        assert_eq!([error.line, error.column], [2, 1])
+    }
+
+    #[test]
+    fn can_parse_non_ascii_cells() {
+        let grid = Grid::parse(12, "éA\nBC\n").unwrap();
+        assert_eq!(grid.size(), [2, 2]);
+        assert_eq!(grid[Point::new(0, 0)], 'é')
+    }
+
+    #[test]
+    fn can_refuse_ragged_non_ascii_grid() {
+        let error = Grid::parse(12, "é\nAB\n").unwrap_err();
+        assert_eq!([error.line, error.column], [2, 1])
+    }
+
+    #[test]
+    fn can_find_neighbours_within_grid() {
+        let grid = Grid::filled(3, 3, 0);
+        let corner: Vec<Point> = grid.neighbours_4(Point::new(0, 0)).collect();
+        let centre: Vec<Point> = grid.neighbours_8(Point::new(1, 1)).collect();
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(centre.len(), 8)
+    }
+
+    #[test]
+    fn can_refuse_offset_outside_grid() {
+        let grid = Grid::filled(2, 2, 0);
+        let point = Point::new(1, 1);
+        assert_eq!(
+            [
+                grid.offset(point, Vector::new(1, 0)),
+                grid.offset(point, Vector::new(-1, -1))
+            ],
+            [None, Some(Point::new(0, 0))]
+        )
+    }
+
+    #[test]
+    fn can_find_rows_and_columns() {
+        let grid = Grid::filled(2, 3, 0);
+        assert_eq!(grid.rows()[2], vec![Point::new(0, 2), Point::new(1, 2)]);
+        assert_eq!(
+            grid.columns()[1],
+            vec![Point::new(1, 0), Point::new(1, 1), Point::new(1, 2)]
+        )
+    }
+
+    #[test]
+    fn can_find_diagonals() {
+        let grid = Grid::filled(3, 2, 0);
+        assert_eq!(
+            grid.diagonals_down_right(),
+            vec![
+                vec![Point::new(0, 1)],
+                vec![Point::new(0, 0), Point::new(1, 1)],
+                vec![Point::new(1, 0), Point::new(2, 1)],
+                vec![Point::new(2, 0)]
+            ]
+        );
+        assert_eq!(
+            grid.diagonals_up_right(),
+            vec![
+                vec![Point::new(0, 0)],
+                vec![Point::new(0, 1), Point::new(1, 0)],
+                vec![Point::new(1, 1), Point::new(2, 0)],
+                vec![Point::new(2, 1)]
+            ]
+        )
+    }
+
+    #[test]
+    fn can_map_and_render_cells() {
+        let grid = Grid::parse(10, "01\n23").unwrap();
+        let heights = grid.map(|c| c.to_digit(10).unwrap());
+        assert_eq!(
+            heights.render(|_, height| if height % 2 == 0 { '#' } else { '.' }),
+            "#.\n#.\n"
+        )
+    }
+}
@@ -1,5 +1,10 @@
+use crate::config::Config;
+use std::env;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 use std::fs::read_to_string;
 use std::io;
+use std::io::Read;
 use std::path::{Path, PathBuf};
 
 pub fn input_to_string<P: AsRef<Path>>(path: P) -> io::Result<String> {
@@ -13,3 +18,212 @@ pub fn file_in_src<P: AsRef<Path>>(path: P) -> PathBuf {
     full_path.push(path);
     full_path
 }
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+    Search,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct InputDir {
+    pub path: PathBuf,
+    pub origin: &'static str,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound { day: u8, tried: Vec<InputDir> },
+    Read(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl InputSource {
+    pub fn from_arg(arg: &str) -> InputSource {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(PathBuf::from(arg))
+        }
+    }
+}
+
+impl InputDir {
+    pub fn day_input(&self, day: u8) -> PathBuf {
+        self.path.join(day_input_path(day))
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound { day, tried } => {
+                write!(f, "No input found for day {day}, tried:")?;
+                for dir in tried {
+                    write!(f, "\n  {} ({})", dir.day_input(*day).display(), dir.origin)?;
+                }
+                Ok(())
+            }
+            InputError::Read(path, e) => write!(f, "Failed reading {}: {e}", path.display()),
+            InputError::Stdin(e) => write!(f, "Failed reading standard input: {e}"),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+pub fn input_dirs(config: &Config) -> Vec<InputDir> {
+    let mut dirs = vec![];
+    if let Some(path) = env::var_os("AOC_INPUT_DIR") {
+        dirs.push(InputDir {
+            path: PathBuf::from(path),
+            origin: "AOC_INPUT_DIR",
+        });
+    }
+    if let Some(path) = &config.input_dir {
+        dirs.push(InputDir {
+            path: path.clone(),
+            origin: "config input_dir",
+        });
+    }
+    dirs.push(InputDir {
+        path: file_in_src(""),
+        origin: "source tree",
+    });
+    dirs
+}
+
+pub fn day_input_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("day{day:02}/input.txt"))
+}
+
+pub fn hash_input(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
+}
+
+pub fn read_day_input(
+    day: u8,
+    source: &InputSource,
+    dirs: &[InputDir],
+) -> Result<String, InputError> {
+    match source {
+        InputSource::Path(path) => {
+            read_to_string(path).map_err(|e| InputError::Read(path.clone(), e))
+        }
+        InputSource::Stdin => {
+            let mut string = String::new();
+            io::stdin()
+                .read_to_string(&mut string)
+                .map_err(InputError::Stdin)?;
+            Ok(string)
+        }
+        InputSource::Search => search_day_input(day, dirs),
+    }
+}
+
+fn search_day_input(day: u8, dirs: &[InputDir]) -> Result<String, InputError> {
+    for dir in dirs {
+        let path = dir.day_input(day);
+        if path.is_file() {
+            return read_to_string(&path).map_err(|e| InputError::Read(path, e));
+        }
+    }
+    Err(InputError::NotFound {
+        day,
+        tried: dirs.to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{create_dir_all, remove_dir_all, write};
+
+    #[test]
+    fn can_read_from_first_dir_with_input() {
+        let empty = test_dir("empty");
+        let full = test_dir("full");
+        create_dir_all(full.join("day07")).unwrap();
+        write(full.join("day07/input.txt"), "190: 10 19").unwrap();
+        let dirs = [input_dir(&empty, "first"), input_dir(&full, "second")];
+        let result = read_day_input(7, &InputSource::Search, &dirs);
+        remove_dir_all(&full).unwrap();
+        assert_eq!(result.unwrap(), "190: 10 19")
+    }
+
+    #[test]
+    fn can_name_every_location_tried() {
+        let dirs = [
+            input_dir(Path::new("/no/such/dir"), "AOC_INPUT_DIR"),
+            input_dir(Path::new("/no/other/dir"), "config input_dir"),
+        ];
+        let error = read_day_input(7, &InputSource::Search, &dirs).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "No input found for day 7, tried:\n  \
+               /no/such/dir/day07/input.txt (AOC_INPUT_DIR)\n  \
+               /no/other/dir/day07/input.txt (config input_dir)"
+        )
+    }
+
+    #[test]
+    fn can_read_explicit_path() {
+        let path = file_in_src("day07/example.txt");
+        let result = read_day_input(7, &InputSource::Path(path), &[]);
+        assert!(result.unwrap().starts_with("190: 10 19"))
+    }
+
+    #[test]
+    fn can_name_missing_explicit_path() {
+        let source = InputSource::Path(PathBuf::from("/no/such/input.txt"));
+        let error = read_day_input(7, &source, &[]).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Failed reading /no/such/input.txt"))
+    }
+
+    #[test]
+    fn can_find_source_from_arg() {
+        assert_eq!(
+            [InputSource::from_arg("-"), InputSource::from_arg("in.txt")],
+            [
+                InputSource::Stdin,
+                InputSource::Path(PathBuf::from("in.txt"))
+            ]
+        )
+    }
+
+    #[test]
+    fn can_fall_back_to_source_tree() {
+        let dirs = input_dirs(&Config {
+            input_dir: Some(PathBuf::from("/srv/aoc")),
+            ..Config::default()
+        });
+        let origins: Vec<&str> = dirs.iter().map(|dir| dir.origin).collect();
+        assert_eq!(origins.last(), Some(&"source tree"));
+        assert!(origins.contains(&"config input_dir"))
+    }
+
+    #[test]
+    fn can_hash_input_with_fnv() {
+        assert_eq!(
+            [hash_input(""), hash_input("a")],
+            ["cbf29ce484222325", "af63dc4c8601ec8c"]
+        )
+    }
+
+    fn test_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-input-{}-{name}", std::process::id()))
+    }
+
+    fn input_dir(path: &Path, origin: &'static str) -> InputDir {
+        InputDir {
+            path: path.to_path_buf(),
+            origin,
+        }
+    }
+}
@@ -1,3 +1,7 @@
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod config;
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -13,4 +17,21 @@ pub mod day12;
 pub mod day13;
 pub mod day14;
 pub mod day15;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
 pub mod input;
+pub mod observe;
+pub mod oracle;
+pub mod parse;
+pub mod pool;
+pub mod property;
+pub mod render;
+pub mod repl;
+pub mod runner;
+pub mod sandbox;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod trace;
+pub mod visualise;
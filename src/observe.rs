@@ -0,0 +1,70 @@
+pub mod log;
+pub mod progress_bar;
+
+use std::sync::OnceLock;
+
+pub trait Observer: Sync {
+    fn started(&self, _task: &str, _total: usize) {}
+    fn progressed(&self, _task: &str, _done: usize, _total: usize) {}
+    fn traced(&self, _task: &str, _message: &str) {}
+    fn finished(&self, _task: &str) {}
+}
+
+pub struct Silent;
+
+impl Observer for Silent {}
+
+static OBSERVER: OnceLock<Box<dyn Observer + Send>> = OnceLock::new();
+
+pub fn install(observer: Box<dyn Observer + Send>) -> bool {
+    OBSERVER.set(observer).is_ok()
+}
+
+pub fn current() -> &'static dyn Observer {
+    match OBSERVER.get() {
+        Some(observer) => observer.as_ref(),
+        None => &Silent,
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    pub struct Recorder {
+        pub events: Mutex<Vec<String>>,
+    }
+
+    impl Observer for Recorder {
+        fn started(&self, task: &str, total: usize) {
+            self.record(format!("{task} started {total}"));
+        }
+        fn progressed(&self, task: &str, done: usize, total: usize) {
+            self.record(format!("{task} {done}/{total}"));
+        }
+        fn traced(&self, task: &str, message: &str) {
+            self.record(format!("{task}: {message}"));
+        }
+        fn finished(&self, task: &str) {
+            self.record(format!("{task} finished"));
+        }
+    }
+
+    impl Recorder {
+        fn record(&self, event: String) {
+            self.events.lock().unwrap().push(event);
+        }
+
+        pub fn events(&self) -> Vec<String> {
+            self.events.lock().unwrap().clone()
+        }
+    }
+
+    #[test]
+    fn can_default_to_silent_observer() {
+        current().started("task", 1);
+        current().finished("task")
+    }
+}
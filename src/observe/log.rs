@@ -0,0 +1,90 @@
+use crate::observe::Observer;
+use serde_json::{json, Value};
+use std::io::{stderr, Stderr, Write};
+use std::sync::Mutex;
+
+pub struct StructuredLog<W: Write + Send> {
+    out: Mutex<W>,
+}
+
+impl StructuredLog<Stderr> {
+    pub fn stderr() -> StructuredLog<Stderr> {
+        StructuredLog::new(stderr())
+    }
+}
+
+impl<W: Write + Send> StructuredLog<W> {
+    pub fn new(out: W) -> StructuredLog<W> {
+        StructuredLog {
+            out: Mutex::new(out),
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out.into_inner().unwrap()
+    }
+
+    fn log(&self, event: Value) {
+        let mut out = self.out.lock().unwrap();
+        let _ = writeln!(out, "{event}");
+    }
+}
+
+impl<W: Write + Send> Observer for StructuredLog<W> {
+    fn started(&self, task: &str, total: usize) {
+        self.log(json!({"event": "started", "task": task, "total": total}));
+    }
+
+    fn progressed(&self, task: &str, done: usize, total: usize) {
+        let percent = |done: usize| done * 100 / total.max(1);
+        if done >= total || percent(done) != percent(done.saturating_sub(1)) {
+            self.log(json!({"event": "progressed", "task": task, "done": done, "total": total}));
+        }
+    }
+
+    fn traced(&self, task: &str, message: &str) {
+        self.log(json!({"event": "traced", "task": task, "message": message}));
+    }
+
+    fn finished(&self, task: &str) {
+        self.log(json!({"event": "finished", "task": task}));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_log_each_event_as_json_line() {
+        let log = StructuredLog::new(Vec::new());
+        log.started("Following plan", 2);
+        log.progressed("Following plan", 1, 2);
+        log.traced("Following plan", "blocked");
+        log.finished("Following plan");
+        let out = String::from_utf8(log.into_inner()).unwrap();
+        let events: Vec<Value> = out
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(events.len(), 4);
+        assert_eq!(events[1]["done"], 1);
+        assert_eq!(events[2]["message"], "blocked");
+        assert_eq!(events[3]["event"], "finished")
+    }
+
+    #[test]
+    fn can_log_progress_once_per_percent() {
+        let log = StructuredLog::new(Vec::new());
+        for done in 1..=10403 {
+            log.progressed("Scanning", done, 10403);
+        }
+        let out = String::from_utf8(log.into_inner()).unwrap();
+        let events: Vec<Value> = out
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(events.len(), 100);
+        assert_eq!(events[99]["done"], 10403)
+    }
+}
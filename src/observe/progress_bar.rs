@@ -0,0 +1,89 @@
+use crate::observe::Observer;
+use std::io::{stderr, Stderr, Write};
+use std::sync::Mutex;
+
+const WIDTH: usize = 30;
+
+pub struct ProgressBar<W: Write + Send> {
+    out: Mutex<Drawn<W>>,
+}
+
+struct Drawn<W> {
+    out: W,
+    filled: Option<usize>,
+}
+
+impl ProgressBar<Stderr> {
+    pub fn stderr() -> ProgressBar<Stderr> {
+        ProgressBar::new(stderr())
+    }
+}
+
+impl<W: Write + Send> ProgressBar<W> {
+    pub fn new(out: W) -> ProgressBar<W> {
+        ProgressBar {
+            out: Mutex::new(Drawn { out, filled: None }),
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out.into_inner().unwrap().out
+    }
+
+    fn draw(&self, task: &str, done: usize, total: usize) {
+        let filled = (done * WIDTH)
+            .checked_div(total)
+            .unwrap_or(WIDTH)
+            .min(WIDTH);
+        let mut drawn = self.out.lock().unwrap();
+        if drawn.filled == Some(filled) && done < total {
+            return;
+        }
+        drawn.filled = Some(filled);
+        let bar = "#".repeat(filled) + &" ".repeat(WIDTH - filled);
+        let _ = write!(drawn.out, "\r{task} [{bar}] {done}/{total}");
+        let _ = drawn.out.flush();
+    }
+}
+
+impl<W: Write + Send> Observer for ProgressBar<W> {
+    fn started(&self, task: &str, total: usize) {
+        self.out.lock().unwrap().filled = None;
+        self.draw(task, 0, total);
+    }
+
+    fn progressed(&self, task: &str, done: usize, total: usize) {
+        self.draw(task, done, total);
+    }
+
+    fn finished(&self, _task: &str) {
+        let mut drawn = self.out.lock().unwrap();
+        let _ = writeln!(drawn.out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_draw_bar_only_when_it_grows() {
+        let bar = ProgressBar::new(Vec::new());
+        bar.started("Blinking", 60);
+        for done in 1..=60 {
+            bar.progressed("Blinking", done, 60);
+        }
+        bar.finished("Blinking");
+        let out = String::from_utf8(bar.into_inner()).unwrap();
+        assert_eq!(out.matches('\r').count(), 31);
+        assert!(out.ends_with(&format!("\rBlinking [{}] 60/60\n", "#".repeat(30))))
+    }
+
+    #[test]
+    fn can_draw_empty_task_as_full() {
+        let bar = ProgressBar::new(Vec::new());
+        bar.started("Nothing", 0);
+        let out = String::from_utf8(bar.into_inner()).unwrap();
+        assert_eq!(out, format!("\rNothing [{}] 0/0", "#".repeat(30)))
+    }
+}
@@ -0,0 +1,238 @@
+use crate::property::{self, Falsified, PropertyError, Shrink, Verdict};
+use crate::solution::Answer;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+pub type Check = fn(&str) -> Option<Answer>;
+
+pub struct Oracle {
+    pub name: &'static str,
+    pub day: u8,
+    pub shrink: Shrink,
+    pub naive: Check,
+    pub optimised: Check,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct CounterExample {
+    pub oracle: &'static str,
+    pub seed: u64,
+    pub input: String,
+    pub naive: Option<Answer>,
+    pub optimised: Option<Answer>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum OracleError {
+    NoGenerator(u8),
+    Disagreed(CounterExample),
+}
+
+pub fn oracles() -> Vec<Oracle> {
+    vec![
+        Oracle {
+            name: "similarity",
+            day: 1,
+            shrink: Shrink::Lines,
+            naive: crate::day01::oracle::count_similarity,
+            optimised: crate::day01::oracle::scan_similarity,
+        },
+        Oracle {
+            name: "compact splitting files",
+            day: 9,
+            shrink: Shrink::Chars,
+            naive: crate::day09::oracle::simulate_splitting_files,
+            optimised: crate::day09::oracle::compact_splitting_files,
+        },
+        Oracle {
+            name: "compact fitting into spaces",
+            day: 9,
+            shrink: Shrink::Chars,
+            naive: crate::day09::oracle::simulate_fitting_into_spaces,
+            optimised: crate::day09::oracle::compact_fitting_into_spaces,
+        },
+        Oracle {
+            name: "count stones",
+            day: 11,
+            shrink: Shrink::Words,
+            naive: crate::day11::oracle::expand_stones,
+            optimised: crate::day11::oracle::count_stones,
+        },
+        Oracle {
+            name: "min tokens",
+            day: 13,
+            shrink: Shrink::Blocks,
+            naive: crate::day13::oracle::try_all_presses,
+            optimised: crate::day13::oracle::intersect_lines,
+        },
+    ]
+}
+
+impl Oracle {
+    pub fn check(&self, seeds: Range<u64>, size: usize) -> Result<(), OracleError> {
+        let agrees = |input: &str| match self.disagrees(input) {
+            Some(_) => Verdict::Fails,
+            None => Verdict::Holds,
+        };
+        match property::check(self.day, self.shrink, seeds, size, agrees) {
+            Ok(()) => Ok(()),
+            Err(PropertyError::NoGenerator(day)) => Err(OracleError::NoGenerator(day)),
+            Err(PropertyError::Unparsed { .. }) => {
+                unreachable!("Oracles treat failures as disagreements")
+            }
+            Err(PropertyError::Falsified(Falsified { seed, input, .. })) => {
+                let (naive, optimised) = self.disagrees(&input).unwrap_or_default();
+                Err(OracleError::Disagreed(CounterExample {
+                    oracle: self.name,
+                    seed,
+                    input,
+                    naive,
+                    optimised,
+                }))
+            }
+        }
+    }
+
+    pub fn disagrees(&self, input: &str) -> Option<(Option<Answer>, Option<Answer>)> {
+        let naive = catch_unwind(AssertUnwindSafe(|| (self.naive)(input)))
+            .ok()
+            .flatten();
+        let optimised = catch_unwind(AssertUnwindSafe(|| (self.optimised)(input)))
+            .ok()
+            .flatten();
+        match (&naive, &optimised) {
+            (Some(naive), Some(optimised)) if naive == optimised => None,
+            _ => Some((naive, optimised)),
+        }
+    }
+}
+
+impl Display for CounterExample {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Oracle {} disagreed for seed {}, shrunk to:",
+            self.oracle, self.seed
+        )?;
+        write!(f, "{}", self.input)?;
+        write!(
+            f,
+            "Naive: {}, optimised: {}",
+            describe(&self.naive),
+            describe(&self.optimised)
+        )
+    }
+}
+
+impl Error for CounterExample {}
+
+impl Display for OracleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OracleError::NoGenerator(day) => write!(f, "No generator for day {day}"),
+            OracleError::Disagreed(example) => write!(f, "{example}"),
+        }
+    }
+}
+
+impl Error for OracleError {}
+
+fn describe(answer: &Option<Answer>) -> String {
+    answer
+        .as_ref()
+        .map_or("failed".to_string(), Answer::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_agree_with_every_oracle() {
+        for oracle in oracles() {
+            if let Err(e) = oracle.check(0..20, 12) {
+                panic!("{e}")
+            }
+        }
+    }
+
+    #[test]
+    fn can_shrink_to_minimal_counter_example() {
+        let oracle = Oracle {
+            name: "broken sum",
+            day: 11,
+            shrink: Shrink::Words,
+            naive: |input| Some(sum_words(input).into()),
+            optimised: |input| {
+                let sum = sum_words(input);
+                Some(if sum > 100000 { sum + 1 } else { sum }.into())
+            },
+        };
+        let error = oracle.check(0..10, 12);
+        let Err(OracleError::Disagreed(example)) = error else {
+            panic!("{error:?}")
+        };
+        assert_eq!(example.seed, 0);
+        assert_eq!(example.input.split_whitespace().count(), 2);
+        assert!(sum_words(&example.input) > 100000)
+    }
+
+    #[test]
+    fn can_report_failed_parse_as_disagreement() {
+        let oracle = Oracle {
+            name: "failing parse",
+            day: 11,
+            shrink: Shrink::Words,
+            naive: |input| Some(sum_words(input).into()),
+            optimised: |_| None,
+        };
+        let error = oracle.check(0..10, 12);
+        let Err(OracleError::Disagreed(example)) = error else {
+            panic!("{error:?}")
+        };
+        assert_eq!(example.input.split_whitespace().count(), 1);
+        assert!(example.to_string().ends_with(", optimised: failed"))
+    }
+
+    #[test]
+    fn can_report_panic_as_disagreement() {
+        let oracle = Oracle {
+            name: "panicking sum",
+            day: 11,
+            shrink: Shrink::Words,
+            naive: |input| Some(sum_words(input).into()),
+            optimised: |input| match sum_words(input) {
+                sum if sum > 100000 => panic!("Sum too large"),
+                sum => Some(sum.into()),
+            },
+        };
+        let error = oracle.check(0..10, 12);
+        let Err(OracleError::Disagreed(example)) = error else {
+            panic!("{error:?}")
+        };
+        assert_eq!(example.input.split_whitespace().count(), 2);
+        assert_eq!(example.optimised, None);
+        assert!(example.to_string().ends_with(", optimised: failed"))
+    }
+
+    #[test]
+    fn can_refuse_to_check_day_without_generator() {
+        let oracle = Oracle {
+            name: "no generator",
+            day: 25,
+            shrink: Shrink::Lines,
+            naive: |_| None,
+            optimised: |_| None,
+        };
+        assert_eq!(oracle.check(0..10, 12), Err(OracleError::NoGenerator(25)))
+    }
+
+    fn sum_words(input: &str) -> usize {
+        input
+            .split_whitespace()
+            .map(|word| word.parse::<usize>().unwrap())
+            .sum()
+    }
+}
@@ -0,0 +1,167 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::str::FromStr;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn at(day: u8, input: &str, text: &str, message: &str) -> ParseError {
+        let [line, column] = position_in(input, text);
+        ParseError {
+            day,
+            line,
+            column,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+    pub fn within(self, outer: &str, inner: &str) -> ParseError {
+        let [line, column] = position_in(outer, inner);
+        ParseError {
+            line: line + self.line - 1,
+            column: if self.line == 1 {
+                column + self.column - 1
+            } else {
+                self.column
+            },
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}, found {:?}",
+            self.day, self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseError> for io::Error {
+    fn from(error: ParseError) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, error)
+    }
+}
+
+pub fn parse_number<T: FromStr>(day: u8, input: &str, text: &str) -> Result<T, ParseError> {
+    T::from_str(text).map_err(|_| ParseError::at(day, input, text, "expected a number"))
+}
+
+pub fn required<'a>(
+    day: u8,
+    input: &str,
+    value: Option<&'a str>,
+    context: &str,
+    message: &str,
+) -> Result<&'a str, ParseError> {
+    value.ok_or_else(|| ParseError::at(day, input, context, message))
+}
+
+pub fn grid_size(day: u8, input: &str) -> Result<[usize; 2], ParseError> {
+    let width = input.lines().next().map_or(0, |line| line.chars().count());
+    let mut height = 0;
+    for line in input.lines() {
+        if line.chars().count() != width {
+            let message = format!("expected a line of width {width}");
+            return Err(ParseError::at(day, input, line, &message));
+        }
+        height += 1;
+    }
+    Ok([width, height])
+}
+
+fn position_in(input: &str, text: &str) -> [usize; 2] {
+    let start = input.as_ptr() as usize;
+    let offset = (text.as_ptr() as usize)
+        .checked_sub(start)
+        .filter(|offset| *offset <= input.len() && input.is_char_boundary(*offset))
+        .unwrap_or(0);
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let column = before[line_start..].chars().count() + 1;
+    [line, column]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_find_position_of_text_in_input() {
+        let input = "12 34\n56 7x";
+        let error = ParseError::at(1, input, &input[9..11], "expected a number");
+        assert_eq!([error.line, error.column], [2, 4])
+    }
+
+    #[test]
+    fn can_find_position_of_text_outside_input() {
+        let other = String::from("7x");
+        let error = ParseError::at(1, "12 34", &other, "expected a number");
+        assert_eq!([error.line, error.column], [1, 1])
+    }
+
+    #[test]
+    fn can_find_position_within_later_line() {
+        let input = "1|2\n\n1,2,x";
+        let updates = &input[5..];
+        let error = parse_number::<i32>(5, updates, &updates[4..]).unwrap_err();
+        let error = error.within(input, updates);
+        assert_eq!([error.line, error.column], [3, 5])
+    }
+
+    #[test]
+    fn can_find_position_within_same_line() {
+        let input = "p=1,x v=1,1";
+        let field = &input[4..5];
+        let error = ParseError::at(14, field, field, "expected a number").within(input, field);
+        assert_eq!([error.line, error.column], [1, 5])
+    }
+
+    #[test]
+    fn can_print_error() {
+        let input = "190: 10 1x";
+        let error = parse_number::<usize>(7, input, &input[8..]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 7, line 1, column 9: expected a number, found \"1x\""
+        )
+    }
+
+    #[test]
+    fn can_find_grid_size() {
+        assert_eq!(grid_size(4, "ABC\nDEF"), Ok([3, 2]))
+    }
+
+    #[test]
+    fn can_refuse_ragged_grid() {
+        let input = "ABC\nDE";
+        let error = grid_size(4, input).unwrap_err();
+        assert_eq!([error.line, error.column], [2, 1]);
+        assert_eq!(error.text, "DE")
+    }
+
+    #[test]
+    fn can_find_grid_size_of_non_ascii_rows() {
+        assert_eq!(grid_size(12, "éA\nBC"), Ok([2, 2]))
+    }
+
+    #[test]
+    fn can_report_width_in_chars_for_ragged_row() {
+        let error = grid_size(12, "é\nAB").unwrap_err();
+        assert_eq!([error.line, error.column], [2, 1]);
+        assert_eq!(error.message, "expected a line of width 1")
+    }
+}
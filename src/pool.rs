@@ -0,0 +1,106 @@
+use crate::trace;
+use std::panic::resume_unwind;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Pool {
+    jobs: usize,
+}
+
+impl Pool {
+    pub fn new(jobs: usize) -> Pool {
+        Pool { jobs: jobs.max(1) }
+    }
+
+    pub fn jobs(&self) -> usize {
+        self.jobs
+    }
+
+    pub fn map<T, R, F>(&self, items: &[T], operation: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync,
+    {
+        if self.jobs == 1 || items.len() < 2 {
+            return items.iter().map(operation).collect();
+        }
+        let next = AtomicUsize::new(0);
+        let context = trace::context();
+        let mut results: Vec<Option<R>> = items.iter().map(|_| None).collect();
+        thread::scope(|scope| {
+            let workers: Vec<_> = (0..self.jobs.min(items.len()))
+                .map(|_| {
+                    let context = context.clone();
+                    scope.spawn(|| {
+                        trace::within(context, || {
+                            let mut done = vec![];
+                            loop {
+                                let index = next.fetch_add(1, Ordering::Relaxed);
+                                let Some(item) = items.get(index) else {
+                                    return done;
+                                };
+                                done.push((index, operation(item)));
+                            }
+                        })
+                    })
+                })
+                .collect();
+            for worker in workers {
+                let done = worker.join().unwrap_or_else(|e| resume_unwind(e));
+                for (index, result) in done {
+                    results[index] = Some(result);
+                }
+            }
+        });
+        results.into_iter().flatten().collect()
+    }
+
+    pub fn sum<T, F>(&self, items: &[T], operation: F) -> usize
+    where
+        T: Sync,
+        F: Fn(&T) -> usize + Sync,
+    {
+        self.map(items, operation).into_iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::catch_unwind;
+
+    #[test]
+    fn can_map_in_order_across_workers() {
+        let items: Vec<usize> = (0..100).collect();
+        let squares = Pool::new(4).map(&items, |n| n * n);
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<usize>>())
+    }
+
+    #[test]
+    fn can_sum_same_as_sequential() {
+        let items: Vec<usize> = (1..=1000).collect();
+        assert_eq!(
+            Pool::new(8).sum(&items, |n| n * 3),
+            Pool::new(1).sum(&items, |n| n * 3)
+        )
+    }
+
+    #[test]
+    fn can_use_at_least_one_job() {
+        assert_eq!(Pool::new(0).jobs(), 1)
+    }
+
+    #[test]
+    fn can_pass_on_panic_from_worker() {
+        let items = [1, 2, 3];
+        let result = catch_unwind(|| {
+            Pool::new(2).map(&items, |n| {
+                assert_ne!(*n, 2);
+                *n
+            })
+        });
+        assert!(result.is_err())
+    }
+}
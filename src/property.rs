@@ -0,0 +1,258 @@
+use crate::generate::generate;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Shrink {
+    Lines,
+    Words,
+    Chars,
+    Blocks,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Verdict {
+    Holds,
+    Fails,
+    Unparsed,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Falsified {
+    pub day: u8,
+    pub seed: u64,
+    pub input: String,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum PropertyError {
+    NoGenerator(u8),
+    Unparsed { day: u8, seed: u64 },
+    Falsified(Falsified),
+}
+
+pub fn check<F>(
+    day: u8,
+    shrink: Shrink,
+    seeds: Range<u64>,
+    size: usize,
+    holds: F,
+) -> Result<(), PropertyError>
+where
+    F: Fn(&str) -> Verdict,
+{
+    for seed in seeds {
+        let Some(generated) = generate(day, seed, size) else {
+            return Err(PropertyError::NoGenerator(day));
+        };
+        match holds(&generated.input) {
+            Verdict::Holds => {}
+            Verdict::Unparsed => return Err(PropertyError::Unparsed { day, seed }),
+            Verdict::Fails => {
+                let fails = |input: &str| holds(input) == Verdict::Fails;
+                let shrunk = shrink.minimise(&generated.input, fails);
+                let input = if fails(&shrunk) {
+                    shrunk
+                } else {
+                    generated.input
+                };
+                return Err(PropertyError::Falsified(Falsified { day, seed, input }));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn round_trips<T, E>(
+    input: &str,
+    parse: fn(&str) -> Result<T, E>,
+    print: fn(&T) -> String,
+) -> Verdict
+where
+    T: PartialEq,
+{
+    let Ok(parsed) = parse(input) else {
+        return Verdict::Unparsed;
+    };
+    match parse(&print(&parsed)) {
+        Ok(reparsed) if reparsed == parsed => Verdict::Holds,
+        _ => Verdict::Fails,
+    }
+}
+
+pub fn assert_round_trips<T, E>(
+    day: u8,
+    shrink: Shrink,
+    parse: fn(&str) -> Result<T, E>,
+    print: fn(&T) -> String,
+) where
+    T: PartialEq,
+{
+    let holds = |input: &str| round_trips(input, parse, print);
+    if let Err(e) = check(day, shrink, 0..20, 20, holds) {
+        panic!("{e}")
+    }
+}
+
+impl Shrink {
+    pub fn minimise<F>(&self, input: &str, fails: F) -> String
+    where
+        F: Fn(&str) -> bool,
+    {
+        let mut units = self.units(input);
+        let mut chunk = (units.len() / 2).max(1);
+        loop {
+            let mut removed = false;
+            let mut start = 0;
+            while start < units.len() && units.len() > 1 {
+                let mut candidate = units.clone();
+                candidate.drain(start..(start + chunk).min(units.len()));
+                if !candidate.is_empty() && fails(&self.join(&candidate)) {
+                    units = candidate;
+                    removed = true;
+                } else {
+                    start += chunk;
+                }
+            }
+            if !removed {
+                if chunk == 1 {
+                    return self.join(&units);
+                }
+                chunk /= 2;
+            }
+        }
+    }
+
+    fn units(&self, input: &str) -> Vec<String> {
+        let units: Vec<&str> = match self {
+            Shrink::Lines => input.lines().collect(),
+            Shrink::Words => input.split_whitespace().collect(),
+            Shrink::Chars => {
+                let input = input.trim_end();
+                input
+                    .char_indices()
+                    .map(|(i, c)| &input[i..i + c.len_utf8()])
+                    .collect()
+            }
+            Shrink::Blocks => input
+                .split("\n\n")
+                .map(str::trim_end)
+                .filter(|block| !block.is_empty())
+                .collect(),
+        };
+        units.into_iter().map(str::to_string).collect()
+    }
+
+    fn join(&self, units: &[String]) -> String {
+        let separator = match self {
+            Shrink::Lines => "\n",
+            Shrink::Words => " ",
+            Shrink::Chars => "",
+            Shrink::Blocks => "\n\n",
+        };
+        format!("{}\n", units.join(separator))
+    }
+}
+
+impl Display for Falsified {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Property failed for day {} seed {}, shrunk to:",
+            self.day, self.seed
+        )?;
+        write!(f, "{}", self.input)
+    }
+}
+
+impl Error for Falsified {}
+
+impl Display for PropertyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PropertyError::NoGenerator(day) => write!(f, "No generator for day {day}"),
+            PropertyError::Unparsed { day, seed } => {
+                write!(
+                    f,
+                    "Generated input for day {day} seed {seed} does not parse"
+                )
+            }
+            PropertyError::Falsified(falsified) => write!(f, "{falsified}"),
+        }
+    }
+}
+
+impl Error for PropertyError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_shrink_lines_and_blocks() {
+        assert_eq!(
+            Shrink::Blocks.units("a\nb\n\nc\n"),
+            ["a\nb".to_string(), "c".to_string()]
+        );
+        assert_eq!(Shrink::Lines.join(&["1".to_string()]), "1\n")
+    }
+
+    #[test]
+    fn can_shrink_to_line_that_fails() {
+        let input = "1\n2\n3\n4\n5\n";
+        assert_eq!(
+            Shrink::Lines.minimise(input, |input| input.contains('4')),
+            "4\n"
+        )
+    }
+
+    #[test]
+    fn can_find_printer_that_loses_data() {
+        let error = check(11, Shrink::Words, 0..5, 10, |input| {
+            round_trips(input, parse_words, |words| words[1..].join(" "))
+        });
+        let Err(PropertyError::Falsified(falsified)) = error else {
+            panic!("{error:?}")
+        };
+        assert_eq!(falsified.seed, 0);
+        assert_eq!(falsified.input.split_whitespace().count(), 1)
+    }
+
+    #[test]
+    fn can_refuse_to_check_day_without_generator() {
+        assert_eq!(
+            check(25, Shrink::Lines, 0..5, 10, |_| Verdict::Holds),
+            Err(PropertyError::NoGenerator(25))
+        )
+    }
+
+    #[test]
+    fn can_refuse_generated_input_that_does_not_parse() {
+        let error = check(11, Shrink::Words, 3..5, 10, |input| {
+            round_trips(
+                input,
+                |_| Err::<Vec<String>, ()>(()),
+                |words| words.join(" "),
+            )
+        });
+        assert_eq!(error, Err(PropertyError::Unparsed { day: 11, seed: 3 }))
+    }
+
+    #[test]
+    fn can_ignore_shrunk_inputs_that_do_not_parse() {
+        let verdict = |input: &str| match input.split_whitespace().count() {
+            1 => Verdict::Unparsed,
+            _ => Verdict::Fails,
+        };
+        let error = check(11, Shrink::Words, 0..1, 10, verdict);
+        let Err(PropertyError::Falsified(falsified)) = error else {
+            panic!("{error:?}")
+        };
+        assert_eq!(falsified.input.split_whitespace().count(), 2)
+    }
+
+    fn parse_words(input: &str) -> Result<Vec<String>, ()> {
+        Ok(input.split_whitespace().map(str::to_string).collect())
+    }
+}
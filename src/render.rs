@@ -0,0 +1,35 @@
+pub mod palette;
+pub mod png;
+pub mod svg;
+
+use crate::grid::Grid;
+use crate::render::palette::Palette;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+
+pub trait Snapshot {
+    fn snapshot(&self, part: u8) -> Grid<char>;
+
+    fn palette(&self) -> Palette {
+        Palette::default()
+    }
+}
+
+#[derive(Debug)]
+pub enum RenderError {
+    Write(PathBuf, io::Error),
+    Encode(PathBuf, String),
+}
+
+impl Display for RenderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenderError::Write(path, e) => write!(f, "Failed writing {}: {e}", path.display()),
+            RenderError::Encode(path, e) => write!(f, "Failed encoding {}: {e}", path.display()),
+        }
+    }
+}
+
+impl Error for RenderError {}
@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+pub type Colour = [u8; 3];
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Palette {
+    background: Colour,
+    colours: BTreeMap<char, Colour>,
+}
+
+impl Palette {
+    pub fn new(background: Colour) -> Palette {
+        Palette {
+            background,
+            colours: BTreeMap::new(),
+        }
+    }
+
+    pub fn with(mut self, tiles: &str, colour: Colour) -> Palette {
+        for tile in tiles.chars() {
+            self.colours.insert(tile, colour);
+        }
+        self
+    }
+
+    pub fn distinct<I>(background: Colour, tiles: I) -> Palette
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let mut palette = Palette::new(background);
+        for tile in tiles {
+            let hue = palette.colours.len() as f64 * 137.508;
+            palette.colours.entry(tile).or_insert(hue_colour(hue));
+        }
+        palette
+    }
+
+    pub fn background(&self) -> Colour {
+        self.background
+    }
+
+    pub fn colour(&self, tile: char) -> Colour {
+        self.colours.get(&tile).copied().unwrap_or(self.background)
+    }
+
+    pub fn colours(&self) -> Vec<Colour> {
+        let mut colours = vec![self.background];
+        colours.extend(self.colours.values());
+        colours
+    }
+
+    pub fn index(&self, tile: char) -> usize {
+        self.colours
+            .keys()
+            .position(|key| *key == tile)
+            .map_or(0, |position| position + 1)
+    }
+
+    pub fn is_background(&self, tile: char) -> bool {
+        self.colour(tile) == self.background
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Palette {
+        Palette::new([15, 15, 35])
+            .with("#", [120, 120, 140])
+            .with("^>v<@", [255, 255, 102])
+            .with("X|-+", [70, 110, 200])
+            .with("O[]", [200, 140, 60])
+            .with("123456789", [0, 204, 0])
+    }
+}
+
+pub fn hex(colour: Colour) -> String {
+    colour.iter().fold(String::from("#"), |mut hex, component| {
+        let _ = write!(hex, "{component:02x}");
+        hex
+    })
+}
+
+fn hue_colour(hue: f64) -> Colour {
+    let sector = (hue % 360.0) / 60.0;
+    let rising = (sector.fract() * 200.0) as u8 + 55;
+    let falling = 255 - rising + 55;
+    match sector as u8 {
+        0 => [255, rising, 55],
+        1 => [falling, 255, 55],
+        2 => [55, 255, rising],
+        3 => [55, falling, 255],
+        4 => [rising, 55, 255],
+        _ => [255, 55, falling],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_look_up_tile_colours() {
+        let palette = Palette::new([0, 0, 0]).with("ab", [255, 0, 0]);
+        assert_eq!(
+            [
+                palette.colour('a'),
+                palette.colour('b'),
+                palette.colour('c')
+            ],
+            [[255, 0, 0], [255, 0, 0], [0, 0, 0]]
+        );
+        assert_eq!([palette.index('a'), palette.index('c')], [1, 0])
+    }
+
+    #[test]
+    fn can_give_each_tile_a_distinct_colour() {
+        let palette = Palette::distinct([0, 0, 0], "ABCAB".chars());
+        let colours = palette.colours();
+        assert_eq!(colours.len(), 4);
+        assert_ne!(palette.colour('A'), palette.colour('B'));
+        assert_ne!(palette.colour('B'), palette.colour('C'))
+    }
+
+    #[test]
+    fn can_format_hex_colour() {
+        assert_eq!(hex([15, 160, 255]), "#0fa0ff")
+    }
+}
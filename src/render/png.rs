@@ -0,0 +1,69 @@
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::render::palette::Palette;
+use crate::render::RenderError;
+use ::png::{BitDepth, ColorType, Encoder};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+pub fn to_pixels(tiles: &Grid<char>, palette: &Palette, cell_pixels: usize) -> Vec<u8> {
+    let [width, height] = tiles.size().map(|length| length * cell_pixels);
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for y in 0..height {
+        for x in 0..width {
+            let tile = tiles[Point::new(x / cell_pixels, y / cell_pixels)];
+            pixels.extend(palette.colour(tile));
+        }
+    }
+    pixels
+}
+
+pub fn encode_png<W: Write>(
+    tiles: &Grid<char>,
+    palette: &Palette,
+    cell_pixels: usize,
+    out: W,
+) -> Result<(), ::png::EncodingError> {
+    let [width, height] = tiles.size().map(|length| (length * cell_pixels) as u32);
+    let mut encoder = Encoder::new(out, width, height);
+    encoder.set_color(ColorType::Rgb);
+    encoder.set_depth(BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&to_pixels(tiles, palette, cell_pixels))?;
+    writer.finish()
+}
+
+pub fn write_png(
+    tiles: &Grid<char>,
+    palette: &Palette,
+    cell_pixels: usize,
+    path: &Path,
+) -> Result<(), RenderError> {
+    let file = File::create(path).map_err(|e| RenderError::Write(path.to_path_buf(), e))?;
+    encode_png(tiles, palette, cell_pixels, BufWriter::new(file))
+        .map_err(|e| RenderError::Encode(path.to_path_buf(), e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_scale_tiles_to_pixels() {
+        let tiles = Grid::parse(0, "#.").unwrap();
+        let palette = Palette::new([0, 0, 0]).with("#", [255, 255, 255]);
+        assert_eq!(
+            to_pixels(&tiles, &palette, 2),
+            [[255; 6], [0; 6], [255; 6], [0; 6]].concat()
+        )
+    }
+
+    #[test]
+    fn can_encode_png() {
+        let tiles = Grid::parse(0, "#.\n.@").unwrap();
+        let mut out = Vec::new();
+        encode_png(&tiles, &Palette::default(), 4, &mut out).unwrap();
+        assert_eq!(&out[1..4], b"PNG")
+    }
+}
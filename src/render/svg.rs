@@ -0,0 +1,59 @@
+use crate::grid::Grid;
+use crate::render::palette::{hex, Palette};
+use crate::render::RenderError;
+use std::fmt::Write;
+use std::fs::write;
+use std::path::Path;
+
+pub fn to_svg(tiles: &Grid<char>, palette: &Palette, cell_pixels: usize) -> String {
+    let [width, height] = tiles.size();
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+         viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">",
+        width * cell_pixels,
+        height * cell_pixels
+    );
+    let _ = writeln!(
+        svg,
+        "<rect width=\"{width}\" height=\"{height}\" fill=\"{}\"/>",
+        hex(palette.background())
+    );
+    for (point, tile) in tiles.iter() {
+        if palette.is_background(*tile) {
+            continue;
+        }
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{}\" y=\"{}\" width=\"1\" height=\"1\" fill=\"{}\"/>",
+            point.x,
+            point.y,
+            hex(palette.colour(*tile))
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+pub fn write_svg(
+    tiles: &Grid<char>,
+    palette: &Palette,
+    cell_pixels: usize,
+    path: &Path,
+) -> Result<(), RenderError> {
+    write(path, to_svg(tiles, palette, cell_pixels))
+        .map_err(|e| RenderError::Write(path.to_path_buf(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn can_draw_non_background_tiles() {
+        let tiles = Grid::parse(0, "#.\n.@").unwrap();
+        assert_snapshot!(to_svg(&tiles, &Palette::default(), 10))
+    }
+}
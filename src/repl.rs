@@ -0,0 +1,263 @@
+use crate::day04::word_search::WordSearch;
+use crate::day06::lab_room::LabRoom;
+use crate::day07::Equation;
+use crate::day08::antenna_map::AntennaMap;
+use crate::day11::blink_stone;
+use crate::day11::stones::Stones;
+use crate::day12::garden_map::GardenMap;
+use crate::day14::robots::Robots;
+use crate::day15::robot_plan::RobotPlan;
+use crate::geometry::Point;
+use crate::render::Snapshot;
+use crate::solution::{solver_for_day, Parsed};
+use crate::visualise::{Frame, Frames, Visualise};
+use std::str::FromStr;
+
+pub const HELP: &str = "\
+Commands:
+  part <1|2>          solve a part
+  print               print the grid at the current step
+  step [n]            step the simulation forward (1 by default)
+  reset               go back to the start of the simulation
+  cell <x> <y>        show the tile at a point in the current grid
+  blink <stone>       day 11: blink a single stone once
+  count <blinks>      day 11: count stones after a number of blinks
+  check <equation>    day 7: check an equation like 190: 10 19
+  help                show this help
+  quit                leave the shell";
+
+pub struct Session<'a> {
+    day: u8,
+    parsed: &'a dyn Parsed,
+    model: Model<'a>,
+    frames: Option<Frames<'a>>,
+    frame: Option<Frame>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Reply {
+    Output(String),
+    Quit,
+}
+
+enum Model<'a> {
+    WordSearch(&'a WordSearch),
+    LabRoom(&'a LabRoom),
+    Equations,
+    AntennaMap(&'a AntennaMap),
+    Stones(&'a Stones),
+    GardenMap(&'a GardenMap),
+    Robots(&'a Robots),
+    RobotPlan(&'a RobotPlan),
+    Other,
+}
+
+pub fn parse(day: u8, input: &str) -> Result<Box<dyn Parsed>, String> {
+    let solver = solver_for_day(day).ok_or(format!("No solver registered for day {day}"))?;
+    solver
+        .parse(input)
+        .map_err(|e| format!("Parse failed: {e}"))
+}
+
+impl<'a> Session<'a> {
+    pub fn new(day: u8, parsed: &'a dyn Parsed) -> Session<'a> {
+        let input = parsed.input();
+        let model = match day {
+            4 => input.downcast_ref().map(Model::WordSearch),
+            6 => input.downcast_ref().map(Model::LabRoom),
+            7 => Some(Model::Equations),
+            8 => input.downcast_ref().map(Model::AntennaMap),
+            11 => input.downcast_ref().map(Model::Stones),
+            12 => input.downcast_ref().map(Model::GardenMap),
+            14 => input.downcast_ref().map(Model::Robots),
+            15 => input.downcast_ref().map(Model::RobotPlan),
+            _ => None,
+        };
+        let mut session = Session {
+            day,
+            parsed,
+            model: model.unwrap_or(Model::Other),
+            frames: None,
+            frame: None,
+        };
+        session.reset();
+        session
+    }
+
+    pub fn prompt(&self) -> String {
+        format!("day{:02}> ", self.day)
+    }
+
+    pub fn execute(&mut self, line: &str) -> Result<Reply, String> {
+        let (command, rest) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+        let args: Vec<&str> = rest.split_whitespace().collect();
+        let output = match (command, args.as_slice()) {
+            ("quit" | "exit", []) => return Ok(Reply::Quit),
+            ("help", []) => HELP.to_string(),
+            ("part", [part]) => match *part {
+                "1" | "2" => match self.parsed.part(parse_arg(part)?) {
+                    Some(answer) => answer.to_string(),
+                    None => return Err(format!("No answer for part {part}")),
+                },
+                other => return Err(format!("Part must be 1 or 2, found: {other}")),
+            },
+            ("print", []) => self.frame()?.to_string(),
+            ("step", []) => self.step_by(1)?.to_string(),
+            ("step", [steps]) => self.step_by(parse_arg(steps)?)?.to_string(),
+            ("reset", []) => {
+                self.reset();
+                self.frame()?.to_string()
+            }
+            ("cell", [x, y]) => {
+                let point = Point::new(parse_arg(x)?, parse_arg(y)?);
+                let tile = self.frame()?.tiles.get(point).copied();
+                tile.ok_or(format!("{x},{y} is outside the grid"))?
+                    .to_string()
+            }
+            ("blink", [stone]) => match self.model {
+                Model::Stones(_) => Stones::new(blink_stone(parse_arg(stone)?)).print(),
+                _ => return Err(self.unavailable(command)),
+            },
+            ("count", [blinks]) => match self.model {
+                Model::Stones(stones) => stones
+                    .count_stones_after_blinks(parse_arg(blinks)?)
+                    .to_string(),
+                _ => return Err(self.unavailable(command)),
+            },
+            ("check", [_, ..]) => match self.model {
+                Model::Equations => {
+                    let equation = Equation::parse(rest).map_err(|e| e.to_string())?;
+                    format!(
+                        "add and multiply: {}\nwith concatenation: {}",
+                        equation.is_possible_add_multiply(),
+                        equation.is_possible_add_multiply_concatenate()
+                    )
+                }
+                _ => return Err(self.unavailable(command)),
+            },
+            ("", []) => String::new(),
+            _ => return Err(format!("Unknown command: {}, try help", line.trim())),
+        };
+        Ok(Reply::Output(output))
+    }
+
+    fn reset(&mut self) {
+        self.frames = self.visualise().map(|visualise| visualise.frames());
+        self.frame = self.frames.as_mut().and_then(Iterator::next);
+    }
+
+    fn step_by(&mut self, steps: usize) -> Result<Frame, String> {
+        let Some(frames) = self.frames.as_mut() else {
+            return Err(self.unavailable("step"));
+        };
+        if let Some(frame) = frames.take(steps).last() {
+            self.frame = Some(frame);
+        }
+        self.frame()
+    }
+
+    fn frame(&self) -> Result<Frame, String> {
+        if self.frames.is_some() {
+            return self
+                .frame
+                .clone()
+                .ok_or("Simulation has no steps".to_string());
+        }
+        let snapshot = self.snapshot().ok_or(self.unavailable("grid"))?;
+        Ok(Frame::new("Part 1", snapshot.snapshot(1)))
+    }
+
+    fn visualise(&self) -> Option<&'a dyn Visualise> {
+        match self.model {
+            Model::LabRoom(lab_room) => Some(lab_room),
+            Model::Robots(robots) => Some(robots),
+            Model::RobotPlan(robot_plan) => Some(robot_plan),
+            _ => None,
+        }
+    }
+
+    fn snapshot(&self) -> Option<&'a dyn Snapshot> {
+        match self.model {
+            Model::WordSearch(word_search) => Some(word_search),
+            Model::AntennaMap(antenna_map) => Some(antenna_map),
+            Model::GardenMap(garden_map) => Some(garden_map),
+            _ => None,
+        }
+    }
+
+    fn unavailable(&self, command: &str) -> String {
+        format!("No {command} for day {}", self.day)
+    }
+}
+
+fn parse_arg<T: FromStr>(arg: &str) -> Result<T, String> {
+    T::from_str(arg).map_err(|_| format!("Expected a number, found: {arg}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::input_to_string;
+
+    fn output(session: &mut Session<'_>, line: &str) -> String {
+        match session.execute(line) {
+            Ok(Reply::Output(output)) => output,
+            other => panic!("{line}: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn can_step_warehouse_and_query_cells() {
+        let input = input_to_string("day15/small-example.txt").unwrap();
+        let parsed = parse(15, &input).unwrap();
+        let mut session = Session::new(15, parsed.as_ref());
+        assert!(output(&mut session, "print").starts_with("Start\n########\n"));
+        assert!(output(&mut session, "step 2").starts_with("Move 2: ^\n"));
+        assert_eq!(output(&mut session, "cell 0 0"), "#");
+        assert_eq!(output(&mut session, "part 1"), "2028");
+        assert!(output(&mut session, "reset").starts_with("Start\n"))
+    }
+
+    #[test]
+    fn can_carry_on_stepping_from_current_frame() {
+        let input = input_to_string("day15/small-example.txt").unwrap();
+        let parsed = parse(15, &input).unwrap();
+        let mut session = Session::new(15, parsed.as_ref());
+        assert!(output(&mut session, "step").starts_with("Move 1: <\n"));
+        assert!(output(&mut session, "step").starts_with("Move 2: ^\n"));
+        assert!(output(&mut session, "print").starts_with("Move 2: ^\n"));
+        assert!(output(&mut session, "step 1000").starts_with("Move 15: <\n"));
+        assert!(output(&mut session, "reset").starts_with("Start\n"))
+    }
+
+    #[test]
+    fn can_blink_single_stone() {
+        let parsed = parse(11, "125 17").unwrap();
+        let mut session = Session::new(11, parsed.as_ref());
+        assert_eq!(output(&mut session, "blink 2024"), "20 24");
+        assert_eq!(output(&mut session, "count 6"), "22")
+    }
+
+    #[test]
+    fn can_check_ad_hoc_equation() {
+        let input = input_to_string("day07/example.txt").unwrap();
+        let parsed = parse(7, &input).unwrap();
+        let mut session = Session::new(7, parsed.as_ref());
+        assert_eq!(
+            output(&mut session, "check 156: 15 6"),
+            "add and multiply: false\nwith concatenation: true"
+        )
+    }
+
+    #[test]
+    fn can_refuse_unavailable_commands() {
+        let parsed = parse(11, "125 17").unwrap();
+        let mut session = Session::new(11, parsed.as_ref());
+        assert_eq!(
+            session.execute("step"),
+            Err("No step for day 11".to_string())
+        );
+        assert!(session.execute("part 3").is_err());
+        assert_eq!(session.execute("quit"), Ok(Reply::Quit))
+    }
+}
@@ -0,0 +1,65 @@
+mod args;
+mod bench;
+mod example;
+mod fetch;
+mod generate;
+mod new;
+mod oracle;
+mod render;
+mod repl;
+mod run;
+mod submit;
+mod verify;
+mod visualise;
+mod watch;
+
+pub use args::{Command, DaySelection, PartSelection};
+pub use bench::BenchOptions;
+pub use generate::GenerateOptions;
+pub use oracle::OracleOptions;
+pub use render::{ImageFormat, RenderOptions};
+pub use run::{Progress, RunOptions};
+pub use visualise::{FrameFormat, VisualiseOptions};
+pub use watch::WatchOptions;
+
+use crate::config::Config;
+use crate::input::input_dirs;
+use args::USAGE;
+use std::process::ExitCode;
+
+pub fn main<I, S>(args: I) -> ExitCode
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let command = match Command::parse(args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::from(2);
+        }
+    };
+    match command {
+        Command::Run { days, options } => run::run(days, &options, &input_dirs(&config)),
+        Command::Fetch { day } => fetch::fetch(day, &config),
+        Command::Submit { day, part, input } => submit::submit(day, part, &input, &config),
+        Command::Verify { record } => verify::verify(record, &config),
+        Command::Bench { days, options } => bench::bench(days, &options, &config),
+        Command::Visualise { day, options } => visualise::visualise(day, &options, &config),
+        Command::Render { day, options } => render::render(day, &options, &config),
+        Command::Generate { day, options } => generate::generate_input(day, &options),
+        Command::Oracle { days, options } => oracle::oracle(days, &options),
+        Command::New { day } => new::new_day(day),
+        Command::Example { day, html } => example::example(day, &html),
+        Command::Watch { day, options } => watch::watch(day, &options, &config),
+        Command::Repl { day, input } => repl::repl(day, &input, &config),
+    }
+}
@@ -0,0 +1,988 @@
+use crate::input::InputSource;
+use crate::runner::bench::BenchOptions;
+use crate::runner::generate::GenerateOptions;
+use crate::runner::oracle::OracleOptions;
+use crate::runner::render::{ImageFormat, RenderOptions};
+use crate::runner::run::{Progress, RunOptions};
+use crate::runner::visualise::{FrameFormat, VisualiseOptions};
+use crate::runner::watch::WatchOptions;
+use crate::sandbox::Limits;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Command {
+    Run {
+        days: DaySelection,
+        options: RunOptions,
+    },
+    Fetch {
+        day: u8,
+    },
+    Submit {
+        day: u8,
+        part: u8,
+        input: InputSource,
+    },
+    Verify {
+        record: bool,
+    },
+    Bench {
+        days: DaySelection,
+        options: BenchOptions,
+    },
+    Visualise {
+        day: u8,
+        options: VisualiseOptions,
+    },
+    Render {
+        day: u8,
+        options: RenderOptions,
+    },
+    Generate {
+        day: u8,
+        options: GenerateOptions,
+    },
+    Oracle {
+        days: DaySelection,
+        options: OracleOptions,
+    },
+    New {
+        day: u8,
+    },
+    Example {
+        day: u8,
+        html: PathBuf,
+    },
+    Watch {
+        day: u8,
+        options: WatchOptions,
+    },
+    Repl {
+        day: u8,
+        input: InputSource,
+    },
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum DaySelection {
+    All,
+    Day(u8),
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum PartSelection {
+    Both,
+    Part(u8),
+}
+
+pub const USAGE: &str = "\
+Usage:
+  aoc run <day|all> [--part <1|2>] [--input <path|->] [--json] [--jobs <n>]
+                    [--progress <bar|log>] [--phases] [--trace <path>]
+                    [--timeout <seconds>] [--memory <mb>]
+  aoc fetch <day>
+  aoc submit <day> <1|2> [--input <path|->]
+  aoc verify [--record]
+  aoc bench <day|all> [--runs <n>] [--save <path>] [--baseline <path>] [--threshold <percent>]
+  aoc visualise <day> [--format <text|ansi|gif>] [--output <path>] [--delay <ms>]
+                      [--frames <n>] [--input <path|->]
+  aoc render <day> [--part <1|2>] [--format <svg|png>] [--output <path>]
+                   [--scale <pixels>] [--input <path|->]
+  aoc generate <day> [--seed <n>] [--size <n>] [--output <path>]
+  aoc oracle <day|all> [--seeds <n>] [--size <n>]
+  aoc new <day>
+  aoc example <day> <puzzle.html>
+  aoc watch <day> [--interval <ms>] [--input <path>]
+  aoc repl <day> [--input <path>]
+
+Running with --json prints one result per part with its answer, type,
+duration, input hash and any error, as a JSON array. With --jobs, days run
+on up to that many threads, reported in order, and the slower loops inside
+them share any threads left over.
+With --progress, the slower loops report progress to stderr as a bar, or as
+JSON lines for a log, logging each percent of progress at most once. With
+--phases, the time spent in each phase marked inside a day is listed after
+its answers, and --trace writes every phase as Chrome trace events for
+chrome://tracing or Perfetto.
+With --timeout or --memory, each day runs in its own process, stopped after
+that many seconds or capped at that much memory, and reported as timed out or
+out of memory while the other days carry on. These limits cannot be combined
+with --progress, --phases or --trace, and --memory is only supported on unix.
+Inputs are read from --input if given, otherwise from dayNN/input.txt under
+$AOC_INPUT_DIR, the input_dir set in aoc.toml, then the source tree.
+Fetching needs a session token from $AOC_SESSION or session in aoc.toml, and
+saves to the first of those directories unless the input is already there.
+Submitted answers are logged to guess_log in aoc.toml, or guesses.tsv in the
+first input directory, so repeated guesses and cooldowns are not sent again.
+Verifying checks every day against the file set as answers in aoc.toml, or
+answers.toml in the first input directory outside the source tree, and
+--record adds any answers not yet confirmed.
+Benchmarking runs each phase 10 times by default, and fails if a median is
+over the threshold (10% by default) slower than the baseline.
+Visualising steps through days 6, 14 and 15 one frame at a time, as text on
+stdout or --output, played in the terminal every --delay (100ms by default),
+or as an animated GIF written to --output or dayNN.gif.
+Rendering draws the state of days 4, 8, 12, 14 and 15 for a part as an image,
+with --scale pixels per cell (10 by default), to --output or dayNN-partN.svg.
+Generating writes a random input to stdout or --output, from --seed (0 by
+default) at --size lines or grid width (100 by default), and prints any
+answers known from how it was built.
+Oracle testing compares naive and optimised solutions on generated inputs for
+days 1, 9, 11 and 13, from seeds 0 to --seeds (100 by default) at --size (20
+by default), and shrinks any disagreement to a minimal counter-example.
+New days are scaffolded into the source tree with an empty example, added to
+lib.rs and the solver registry, and never overwrite existing files.
+Examples are taken from the first code block of a saved puzzle page, written
+to an empty dayNN/example.txt, with a test for each emphasised answer.
+Watching polls the day's source folder and inputs every --interval (500ms by
+default), and on each change re-runs its tests and solver with cargo, showing
+how the answers differ from the previous run.
+The shell loads a day's input to step its simulation, print its grid, query
+cells, solve either part or try functions such as blink on other values.";
+
+impl Command {
+    pub fn parse<I, S>(args: I) -> Result<Command, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let args: Vec<String> = args.into_iter().map(|a| a.as_ref().to_string()).collect();
+        let mut iter = args.iter();
+        match iter.next().map(String::as_str) {
+            Some("run") => parse_run(iter),
+            Some("fetch") => parse_fetch(iter),
+            Some("submit") => parse_submit(iter),
+            Some("verify") => parse_verify(iter),
+            Some("bench") => parse_bench(iter),
+            Some("visualise") => parse_visualise(iter),
+            Some("render") => parse_render(iter),
+            Some("generate") => parse_generate(iter),
+            Some("oracle") => parse_oracle(iter),
+            Some("new") => parse_new(iter),
+            Some("example") => parse_example(iter),
+            Some("watch") => parse_watch(iter),
+            Some("repl") => parse_repl(iter),
+            Some(other) => Err(format!("Unknown command: {other}")),
+            None => Err("No command given".to_string()),
+        }
+    }
+}
+
+impl DaySelection {
+    pub fn contains(&self, day: u8) -> bool {
+        match self {
+            DaySelection::All => true,
+            DaySelection::Day(selected) => *selected == day,
+        }
+    }
+}
+
+impl PartSelection {
+    pub fn contains(&self, part: u8) -> bool {
+        match self {
+            PartSelection::Both => true,
+            PartSelection::Part(selected) => *selected == part,
+        }
+    }
+}
+
+fn parse_run<'a, I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let days = parse_days(args.next().ok_or("No day given")?)?;
+    let mut options = RunOptions {
+        part: PartSelection::Both,
+        input: InputSource::Search,
+        json: false,
+        jobs: 1,
+        progress: Progress::Silent,
+        phases: false,
+        trace: None,
+        limits: Limits::default(),
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => options.part = parse_part(args.next().ok_or("No part given")?)?,
+            "--json" => options.json = true,
+            "--jobs" | "-j" => options.jobs = parse_count(args.next().ok_or("No jobs given")?)?,
+            "--progress" => {
+                options.progress = parse_progress(args.next().ok_or("No progress given")?)?;
+            }
+            "--phases" => options.phases = true,
+            "--timeout" => {
+                let seconds = parse_count(args.next().ok_or("No timeout given")?)?;
+                options.limits.timeout = Some(Duration::from_secs(seconds as u64));
+            }
+            "--memory" => {
+                let memory_mb = parse_memory(args.next().ok_or("No memory given")?)?;
+                options.limits.memory_mb = Some(memory_mb);
+            }
+            "--trace" => options.trace = Some(PathBuf::from(args.next().ok_or("No trace given")?)),
+            "--input" | "-i" => {
+                options.input = InputSource::from_arg(args.next().ok_or("No input given")?);
+            }
+            other => return Err(format!("Unexpected argument: {other}")),
+        }
+    }
+    if days == DaySelection::All && options.input != InputSource::Search {
+        return Err("An input can only be given when running one day".to_string());
+    }
+    let observed =
+        options.progress != Progress::Silent || options.phases || options.trace.is_some();
+    if options.limits.is_limited() && observed {
+        return Err(
+            "--progress, --phases and --trace cannot be used with --timeout or --memory"
+                .to_string(),
+        );
+    }
+    Ok(Command::Run { days, options })
+}
+
+fn parse_memory(string: &str) -> Result<u64, String> {
+    if cfg!(not(unix)) {
+        return Err("--memory is only supported on unix".to_string());
+    }
+    let memory_mb = u64::try_from(parse_count(string)?).ok();
+    match memory_mb.filter(|memory_mb| memory_mb.checked_mul(1024 * 1024).is_some()) {
+        Some(memory_mb) => Ok(memory_mb),
+        None => Err(format!("Memory is too large to limit, found: {string}")),
+    }
+}
+
+fn parse_progress(string: &str) -> Result<Progress, String> {
+    match string {
+        "bar" => Ok(Progress::Bar),
+        "log" => Ok(Progress::Log),
+        _ => Err(format!("Progress must be bar or log, found: {string}")),
+    }
+}
+
+fn parse_fetch<'a, I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let day = parse_day(args.next().ok_or("No day given")?)?;
+    match args.next() {
+        Some(other) => Err(format!("Unexpected argument: {other}")),
+        None => Ok(Command::Fetch { day }),
+    }
+}
+
+fn parse_submit<'a, I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let day = parse_day(args.next().ok_or("No day given")?)?;
+    let part = parse_part_number(args.next().ok_or("No part given")?)?;
+    let mut input = InputSource::Search;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => {
+                input = InputSource::from_arg(args.next().ok_or("No input given")?);
+            }
+            other => return Err(format!("Unexpected argument: {other}")),
+        }
+    }
+    Ok(Command::Submit { day, part, input })
+}
+
+fn parse_verify<'a, I>(args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let mut record = false;
+    for arg in args {
+        match arg.as_str() {
+            "--record" => record = true,
+            other => return Err(format!("Unexpected argument: {other}")),
+        }
+    }
+    Ok(Command::Verify { record })
+}
+
+fn parse_bench<'a, I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let days = parse_days(args.next().ok_or("No day given")?)?;
+    let mut options = BenchOptions {
+        runs: 10,
+        save: None,
+        baseline: None,
+        threshold_percent: 10,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => options.runs = parse_count(args.next().ok_or("No runs given")?)?,
+            "--save" => options.save = Some(PathBuf::from(args.next().ok_or("No path given")?)),
+            "--baseline" => {
+                options.baseline = Some(PathBuf::from(args.next().ok_or("No path given")?));
+            }
+            "--threshold" => {
+                let threshold = args.next().ok_or("No threshold given")?;
+                options.threshold_percent = u32::from_str(threshold.trim_end_matches('%'))
+                    .map_err(|_| format!("Threshold must be a percentage, found: {threshold}"))?;
+            }
+            other => return Err(format!("Unexpected argument: {other}")),
+        }
+    }
+    Ok(Command::Bench { days, options })
+}
+
+fn parse_visualise<'a, I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let day = parse_day(args.next().ok_or("No day given")?)?;
+    let mut options = VisualiseOptions {
+        format: FrameFormat::Text,
+        output: None,
+        delay_ms: 100,
+        frames: None,
+        input: InputSource::Search,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" | "-f" => {
+                options.format = parse_format(args.next().ok_or("No format given")?)?;
+            }
+            "--output" | "-o" => {
+                options.output = Some(PathBuf::from(args.next().ok_or("No path given")?));
+            }
+            "--delay" => {
+                let delay = args.next().ok_or("No delay given")?;
+                options.delay_ms = u64::from_str(delay.trim_end_matches("ms"))
+                    .map_err(|_| format!("Delay must be in milliseconds, found: {delay}"))?;
+            }
+            "--frames" => {
+                options.frames = Some(parse_count(args.next().ok_or("No frames given")?)?);
+            }
+            "--input" | "-i" => {
+                options.input = InputSource::from_arg(args.next().ok_or("No input given")?);
+            }
+            other => return Err(format!("Unexpected argument: {other}")),
+        }
+    }
+    if options.format == FrameFormat::Ansi && options.output.is_some() {
+        return Err("ANSI playback is only written to the terminal".to_string());
+    }
+    Ok(Command::Visualise { day, options })
+}
+
+fn parse_format(string: &str) -> Result<FrameFormat, String> {
+    match string {
+        "text" => Ok(FrameFormat::Text),
+        "ansi" => Ok(FrameFormat::Ansi),
+        "gif" => Ok(FrameFormat::Gif),
+        _ => Err(format!("Format must be text, ansi or gif, found: {string}")),
+    }
+}
+
+fn parse_render<'a, I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let day = parse_day(args.next().ok_or("No day given")?)?;
+    let mut options = RenderOptions {
+        part: 1,
+        format: ImageFormat::Svg,
+        output: None,
+        cell_pixels: 10,
+        input: InputSource::Search,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                options.part = parse_part_number(args.next().ok_or("No part given")?)?
+            }
+            "--format" | "-f" => {
+                options.format = match args.next().ok_or("No format given")?.as_str() {
+                    "svg" => ImageFormat::Svg,
+                    "png" => ImageFormat::Png,
+                    other => return Err(format!("Format must be svg or png, found: {other}")),
+                };
+            }
+            "--output" | "-o" => {
+                options.output = Some(PathBuf::from(args.next().ok_or("No path given")?));
+            }
+            "--scale" => options.cell_pixels = parse_count(args.next().ok_or("No scale given")?)?,
+            "--input" | "-i" => {
+                options.input = InputSource::from_arg(args.next().ok_or("No input given")?);
+            }
+            other => return Err(format!("Unexpected argument: {other}")),
+        }
+    }
+    Ok(Command::Render { day, options })
+}
+
+fn parse_generate<'a, I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let day = parse_day(args.next().ok_or("No day given")?)?;
+    let mut options = GenerateOptions {
+        seed: 0,
+        size: 100,
+        output: None,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let seed = args.next().ok_or("No seed given")?;
+                options.seed = u64::from_str(seed)
+                    .map_err(|_| format!("Seed must be a number, found: {seed}"))?;
+            }
+            "--size" => options.size = parse_count(args.next().ok_or("No size given")?)?,
+            "--output" | "-o" => {
+                options.output = Some(PathBuf::from(args.next().ok_or("No path given")?));
+            }
+            other => return Err(format!("Unexpected argument: {other}")),
+        }
+    }
+    Ok(Command::Generate { day, options })
+}
+
+fn parse_oracle<'a, I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let days = parse_days(args.next().ok_or("No day given")?)?;
+    let mut options = OracleOptions {
+        seeds: 100,
+        size: 20,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seeds" => options.seeds = parse_count(args.next().ok_or("No seeds given")?)?,
+            "--size" => options.size = parse_count(args.next().ok_or("No size given")?)?,
+            other => return Err(format!("Unexpected argument: {other}")),
+        }
+    }
+    Ok(Command::Oracle { days, options })
+}
+
+fn parse_new<'a, I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let day = parse_day(args.next().ok_or("No day given")?)?;
+    match args.next() {
+        Some(other) => Err(format!("Unexpected argument: {other}")),
+        None => Ok(Command::New { day }),
+    }
+}
+
+fn parse_example<'a, I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let day = parse_day(args.next().ok_or("No day given")?)?;
+    let html = PathBuf::from(args.next().ok_or("No puzzle page given")?);
+    match args.next() {
+        Some(other) => Err(format!("Unexpected argument: {other}")),
+        None => Ok(Command::Example { day, html }),
+    }
+}
+
+fn parse_watch<'a, I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let day = parse_day(args.next().ok_or("No day given")?)?;
+    let mut options = WatchOptions {
+        interval_ms: 500,
+        input: InputSource::Search,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--interval" => {
+                options.interval_ms = parse_count(args.next().ok_or("No interval given")?)? as u64;
+            }
+            "--input" | "-i" => {
+                options.input = InputSource::from_arg(args.next().ok_or("No input given")?);
+            }
+            other => return Err(format!("Unexpected argument: {other}")),
+        }
+    }
+    if options.input == InputSource::Stdin {
+        return Err("Standard input cannot be watched".to_string());
+    }
+    Ok(Command::Watch { day, options })
+}
+
+fn parse_repl<'a, I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let day = parse_day(args.next().ok_or("No day given")?)?;
+    let mut input = InputSource::Search;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => input = InputSource::from_arg(args.next().ok_or("No input given")?),
+            other => return Err(format!("Unexpected argument: {other}")),
+        }
+    }
+    if input == InputSource::Stdin {
+        return Err("Standard input is used for the shell's commands".to_string());
+    }
+    Ok(Command::Repl { day, input })
+}
+
+fn parse_count(string: &str) -> Result<usize, String> {
+    match usize::from_str(string) {
+        Ok(count) if count > 0 => Ok(count),
+        _ => Err(format!("Expected a positive number, found: {string}")),
+    }
+}
+
+fn parse_days(string: &str) -> Result<DaySelection, String> {
+    if string == "all" {
+        return Ok(DaySelection::All);
+    }
+    match u8::from_str(string) {
+        Ok(day) if (1..=25).contains(&day) => Ok(DaySelection::Day(day)),
+        _ => Err(format!("Day must be 1-25 or all, found: {string}")),
+    }
+}
+
+fn parse_day(string: &str) -> Result<u8, String> {
+    match u8::from_str(string) {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("Day must be 1-25, found: {string}")),
+    }
+}
+
+fn parse_part(string: &str) -> Result<PartSelection, String> {
+    parse_part_number(string).map(PartSelection::Part)
+}
+
+fn parse_part_number(string: &str) -> Result<u8, String> {
+    match string {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("Part must be 1 or 2, found: {string}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_run_one_day() {
+        assert_eq!(
+            Command::parse(["run", "7"]),
+            Ok(Command::Run {
+                days: DaySelection::Day(7),
+                options: RunOptions {
+                    part: PartSelection::Both,
+                    input: InputSource::Search,
+                    json: false,
+                    jobs: 1,
+                    progress: Progress::Silent,
+                    phases: false,
+                    trace: None,
+                    limits: Limits::default()
+                }
+            })
+        )
+    }
+
+    #[test]
+    fn can_parse_run_one_part() {
+        assert_eq!(
+            Command::parse(["run", "7", "--part", "2"]),
+            Ok(Command::Run {
+                days: DaySelection::Day(7),
+                options: RunOptions {
+                    part: PartSelection::Part(2),
+                    input: InputSource::Search,
+                    json: false,
+                    jobs: 1,
+                    progress: Progress::Silent,
+                    phases: false,
+                    trace: None,
+                    limits: Limits::default()
+                }
+            })
+        )
+    }
+
+    #[test]
+    fn can_parse_run_as_json() {
+        assert_eq!(
+            Command::parse(["run", "all", "--json"]),
+            Ok(Command::Run {
+                days: DaySelection::All,
+                options: RunOptions {
+                    part: PartSelection::Both,
+                    input: InputSource::Search,
+                    json: true,
+                    jobs: 1,
+                    progress: Progress::Silent,
+                    phases: false,
+                    trace: None,
+                    limits: Limits::default()
+                }
+            })
+        )
+    }
+
+    #[test]
+    fn can_parse_run_with_progress() {
+        assert_eq!(
+            Command::parse(["run", "14", "--progress", "bar"]),
+            Ok(Command::Run {
+                days: DaySelection::Day(14),
+                options: RunOptions {
+                    part: PartSelection::Both,
+                    input: InputSource::Search,
+                    json: false,
+                    jobs: 1,
+                    progress: Progress::Bar,
+                    phases: false,
+                    trace: None,
+                    limits: Limits::default()
+                }
+            })
+        );
+        assert!(Command::parse(["run", "14", "--progress", "dots"]).is_err())
+    }
+
+    #[test]
+    fn can_parse_run_with_phases_and_trace() {
+        assert_eq!(
+            Command::parse(["run", "12", "--phases", "--trace", "trace.json"]),
+            Ok(Command::Run {
+                days: DaySelection::Day(12),
+                options: RunOptions {
+                    part: PartSelection::Both,
+                    input: InputSource::Search,
+                    json: false,
+                    jobs: 1,
+                    progress: Progress::Silent,
+                    phases: true,
+                    trace: Some(PathBuf::from("trace.json")),
+                    limits: Limits::default()
+                }
+            })
+        )
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn can_parse_run_with_limits() {
+        assert_eq!(
+            Command::parse(["run", "all", "--timeout", "30", "--memory", "512"]),
+            Ok(Command::Run {
+                days: DaySelection::All,
+                options: RunOptions {
+                    part: PartSelection::Both,
+                    input: InputSource::Search,
+                    json: false,
+                    jobs: 1,
+                    progress: Progress::Silent,
+                    phases: false,
+                    trace: None,
+                    limits: Limits {
+                        timeout: Some(Duration::from_secs(30)),
+                        memory_mb: Some(512)
+                    }
+                }
+            })
+        )
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn can_refuse_run_with_memory_too_large() {
+        assert_eq!(
+            Command::parse(["run", "all", "--memory", "17592186044416"]),
+            Err("Memory is too large to limit, found: 17592186044416".to_string())
+        )
+    }
+
+    #[test]
+    #[cfg(not(unix))]
+    fn can_refuse_run_with_memory_off_unix() {
+        assert_eq!(
+            Command::parse(["run", "all", "--memory", "512"]),
+            Err("--memory is only supported on unix".to_string())
+        )
+    }
+
+    #[test]
+    fn can_refuse_run_with_limits_and_observing() {
+        for observing in [["--progress", "bar"], ["--trace", "trace.json"]] {
+            let mut args = vec!["run", "all", "--timeout", "30"];
+            args.extend(observing);
+            assert_eq!(
+                Command::parse(args),
+                Err(
+                    "--progress, --phases and --trace cannot be used with --timeout or --memory"
+                        .to_string()
+                )
+            )
+        }
+        assert!(Command::parse(["run", "all", "--memory", "512", "--phases"]).is_err())
+    }
+
+    #[test]
+    fn can_parse_run_with_jobs() {
+        assert_eq!(
+            Command::parse(["run", "all", "--jobs", "8"]),
+            Ok(Command::Run {
+                days: DaySelection::All,
+                options: RunOptions {
+                    part: PartSelection::Both,
+                    input: InputSource::Search,
+                    json: false,
+                    jobs: 8,
+                    progress: Progress::Silent,
+                    phases: false,
+                    trace: None,
+                    limits: Limits::default()
+                }
+            })
+        )
+    }
+
+    #[test]
+    fn can_parse_run_all() {
+        assert_eq!(
+            Command::parse(["run", "all"]),
+            Ok(Command::Run {
+                days: DaySelection::All,
+                options: RunOptions {
+                    part: PartSelection::Both,
+                    input: InputSource::Search,
+                    json: false,
+                    jobs: 1,
+                    progress: Progress::Silent,
+                    phases: false,
+                    trace: None,
+                    limits: Limits::default()
+                }
+            })
+        )
+    }
+
+    #[test]
+    fn can_parse_run_with_input_from_stdin() {
+        assert_eq!(
+            Command::parse(["run", "7", "--input", "-"]),
+            Ok(Command::Run {
+                days: DaySelection::Day(7),
+                options: RunOptions {
+                    part: PartSelection::Both,
+                    input: InputSource::Stdin,
+                    json: false,
+                    jobs: 1,
+                    progress: Progress::Silent,
+                    phases: false,
+                    trace: None,
+                    limits: Limits::default()
+                }
+            })
+        )
+    }
+
+    #[test]
+    fn can_refuse_input_for_all_days() {
+        assert!(Command::parse(["run", "all", "--input", "in.txt"]).is_err())
+    }
+
+    #[test]
+    fn can_refuse_day_out_of_range() {
+        assert!(Command::parse(["run", "26"]).is_err())
+    }
+
+    #[test]
+    fn can_refuse_part_out_of_range() {
+        assert!(Command::parse(["run", "1", "--part", "3"]).is_err())
+    }
+
+    #[test]
+    fn can_parse_fetch() {
+        assert_eq!(
+            Command::parse(["fetch", "3"]),
+            Ok(Command::Fetch { day: 3 })
+        )
+    }
+
+    #[test]
+    fn can_refuse_fetch_all() {
+        assert!(Command::parse(["fetch", "all"]).is_err())
+    }
+
+    #[test]
+    fn can_parse_submit() {
+        assert_eq!(
+            Command::parse(["submit", "7", "2", "-i", "in.txt"]),
+            Ok(Command::Submit {
+                day: 7,
+                part: 2,
+                input: InputSource::Path(PathBuf::from("in.txt"))
+            })
+        )
+    }
+
+    #[test]
+    fn can_refuse_submit_without_part() {
+        assert!(Command::parse(["submit", "7"]).is_err())
+    }
+
+    #[test]
+    fn can_parse_verify() {
+        assert_eq!(
+            [
+                Command::parse(["verify"]),
+                Command::parse(["verify", "--record"])
+            ],
+            [
+                Ok(Command::Verify { record: false }),
+                Ok(Command::Verify { record: true })
+            ]
+        )
+    }
+
+    #[test]
+    fn can_parse_bench_against_baseline() {
+        assert_eq!(
+            Command::parse(["bench", "6", "--runs", "5", "--baseline", "b.json"]),
+            Ok(Command::Bench {
+                days: DaySelection::Day(6),
+                options: BenchOptions {
+                    runs: 5,
+                    save: None,
+                    baseline: Some(PathBuf::from("b.json")),
+                    threshold_percent: 10
+                }
+            })
+        )
+    }
+
+    #[test]
+    fn can_refuse_zero_bench_runs() {
+        assert!(Command::parse(["bench", "all", "--runs", "0"]).is_err())
+    }
+
+    #[test]
+    fn can_parse_visualise_as_gif() {
+        assert_eq!(
+            Command::parse(["visualise", "14", "--format", "gif", "--frames", "50"]),
+            Ok(Command::Visualise {
+                day: 14,
+                options: VisualiseOptions {
+                    format: FrameFormat::Gif,
+                    output: None,
+                    delay_ms: 100,
+                    frames: Some(50),
+                    input: InputSource::Search
+                }
+            })
+        )
+    }
+
+    #[test]
+    fn can_refuse_ansi_visualisation_to_file() {
+        assert!(Command::parse(["visualise", "6", "-f", "ansi", "-o", "out.txt"]).is_err())
+    }
+
+    #[test]
+    fn can_parse_render_as_png() {
+        assert_eq!(
+            Command::parse(["render", "14", "--part", "2", "-f", "png", "--scale", "4"]),
+            Ok(Command::Render {
+                day: 14,
+                options: RenderOptions {
+                    part: 2,
+                    format: ImageFormat::Png,
+                    output: None,
+                    cell_pixels: 4,
+                    input: InputSource::Search
+                }
+            })
+        )
+    }
+
+    #[test]
+    fn can_parse_generate() {
+        assert_eq!(
+            Command::parse(["generate", "9", "--seed", "42", "--size", "5000"]),
+            Ok(Command::Generate {
+                day: 9,
+                options: GenerateOptions {
+                    seed: 42,
+                    size: 5000,
+                    output: None
+                }
+            })
+        )
+    }
+
+    #[test]
+    fn can_parse_oracle() {
+        assert_eq!(
+            Command::parse(["oracle", "all", "--seeds", "500"]),
+            Ok(Command::Oracle {
+                days: DaySelection::All,
+                options: OracleOptions {
+                    seeds: 500,
+                    size: 20
+                }
+            })
+        )
+    }
+
+    #[test]
+    fn can_parse_new() {
+        assert_eq!(Command::parse(["new", "16"]), Ok(Command::New { day: 16 }))
+    }
+
+    #[test]
+    fn can_parse_watch() {
+        assert_eq!(
+            Command::parse(["watch", "5", "--interval", "200", "-i", "day05.txt"]),
+            Ok(Command::Watch {
+                day: 5,
+                options: WatchOptions {
+                    interval_ms: 200,
+                    input: InputSource::Path(PathBuf::from("day05.txt"))
+                }
+            })
+        );
+        assert!(Command::parse(["watch", "5", "--input", "-"]).is_err())
+    }
+
+    #[test]
+    fn can_parse_repl() {
+        assert_eq!(
+            Command::parse(["repl", "15", "--input", "small.txt"]),
+            Ok(Command::Repl {
+                day: 15,
+                input: InputSource::Path(PathBuf::from("small.txt"))
+            })
+        );
+        assert!(Command::parse(["repl", "15", "-i", "-"]).is_err())
+    }
+
+    #[test]
+    fn can_parse_example() {
+        assert_eq!(
+            Command::parse(["example", "16", "day16.html"]),
+            Ok(Command::Example {
+                day: 16,
+                html: PathBuf::from("day16.html")
+            })
+        )
+    }
+
+    #[test]
+    fn can_refuse_unknown_command() {
+        assert!(Command::parse(["walk", "1"]).is_err())
+    }
+}
@@ -0,0 +1,136 @@
+use crate::bench::{Report, Stats};
+use crate::config::Config;
+use crate::input::{input_dirs, read_day_input, InputError, InputSource};
+use crate::runner::run::time;
+use crate::runner::DaySelection;
+use crate::solution::{Solver, DAYS};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BenchOptions {
+    pub runs: usize,
+    pub save: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+    pub threshold_percent: u32,
+}
+
+pub fn bench(days: DaySelection, options: &BenchOptions, config: &Config) -> ExitCode {
+    let baseline = match options.baseline.as_deref().map(Report::load).transpose() {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::from(2);
+        }
+    };
+    let dirs = input_dirs(config);
+    let mut report = Report::default();
+    let mut failed = false;
+    for solver in DAYS.iter().filter(|solver| days.contains(solver.day())) {
+        println!("Day {:02}", solver.day());
+        let input = match read_day_input(solver.day(), &InputSource::Search, &dirs) {
+            Ok(input) => input,
+            Err(InputError::NotFound { .. }) => {
+                println!("  No input");
+                continue;
+            }
+            Err(e) => {
+                println!("  {e}");
+                failed = true;
+                continue;
+            }
+        };
+        match bench_day(*solver, &input, options.runs) {
+            Ok(phases) => {
+                for (phase, stats) in phases {
+                    println!("  {phase:<6} {stats}");
+                    report.insert(solver.day(), phase, stats);
+                }
+            }
+            Err(message) => {
+                println!("  {message}");
+                failed = true;
+            }
+        }
+    }
+    if let Some(path) = &options.save {
+        if let Err(e) = report.save(path) {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+        println!("Saved report to {}", path.display());
+    }
+    if let Some(baseline) = baseline {
+        let threshold = options.threshold_percent as f64 / 100.0;
+        let comparisons = report.compare(&baseline, threshold);
+        println!("Compared with baseline:");
+        for comparison in &comparisons {
+            println!("  {comparison}");
+        }
+        failed |= comparisons.iter().any(|comparison| comparison.regressed);
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench_day(
+    solver: &dyn Solver,
+    input: &str,
+    runs: usize,
+) -> Result<Vec<(&'static str, Stats)>, String> {
+    let mut samples = vec![];
+    let mut parsed = None;
+    for _ in 0..runs {
+        let (result, duration) = time(|| solver.parse(input));
+        match result {
+            Some(Ok(result)) => parsed = Some(result),
+            Some(Err(e)) => return Err(format!("Parse failed: {e}")),
+            None => return Err("Parse failed".to_string()),
+        }
+        samples.push(duration);
+    }
+    let mut phases = vec![("parse", Stats::from_samples(&samples))];
+    let Some(parsed) = parsed else {
+        return Ok(phases);
+    };
+    for (part, phase) in [(1, "part_1"), (2, "part_2")] {
+        let mut samples = vec![];
+        for _ in 0..runs {
+            let (result, duration) = time(|| parsed.part(part));
+            if result.flatten().is_none() {
+                return Err(format!("Part {part} failed"));
+            }
+            samples.push(duration);
+        }
+        phases.push((phase, Stats::from_samples(&samples)));
+    }
+    Ok(phases)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::input_to_string;
+    use crate::solution::solver_for_day;
+
+    #[test]
+    fn can_bench_every_phase() {
+        let solver = solver_for_day(7).unwrap();
+        let input = input_to_string("day07/example.txt").unwrap();
+        let phases = bench_day(solver, &input, 3).unwrap();
+        let runs: Vec<(&str, usize)> = phases
+            .iter()
+            .map(|(phase, stats)| (*phase, stats.runs))
+            .collect();
+        assert_eq!(runs, vec![("parse", 3), ("part_1", 3), ("part_2", 3)])
+    }
+
+    #[test]
+    fn can_report_parse_failure() {
+        let solver = solver_for_day(7).unwrap();
+        assert!(bench_day(solver, "190 10 19", 3).is_err())
+    }
+}
@@ -0,0 +1,18 @@
+use crate::scaffold::example::extract_example;
+use std::path::Path;
+use std::process::ExitCode;
+
+pub fn example(day: u8, html_path: &Path) -> ExitCode {
+    match extract_example(Path::new(env!("CARGO_MANIFEST_DIR")), day, html_path) {
+        Ok(paths) => {
+            for path in paths {
+                println!("Wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
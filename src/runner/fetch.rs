@@ -0,0 +1,29 @@
+use crate::client::fetch::{fetch_day_input, Fetched};
+use crate::client::Client;
+use crate::config::Config;
+use crate::input::input_dirs;
+use std::process::ExitCode;
+
+pub fn fetch(day: u8, config: &Config) -> ExitCode {
+    let client = match Client::from_config(config) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::from(2);
+        }
+    };
+    match fetch_day_input(&client, day, &input_dirs(config)) {
+        Ok(Fetched::Downloaded(path)) => {
+            println!("Day {day:02}: downloaded to {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Ok(Fetched::AlreadyCached(path)) => {
+            println!("Day {day:02}: already cached at {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Day {day:02}: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::generate::generate;
+use std::fs::write;
+use std::io::{stdout, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct GenerateOptions {
+    pub seed: u64,
+    pub size: usize,
+    pub output: Option<PathBuf>,
+}
+
+pub fn generate_input(day: u8, options: &GenerateOptions) -> ExitCode {
+    let Some(generated) = generate(day, options.seed, options.size) else {
+        eprintln!("No generator for day {day}");
+        return ExitCode::FAILURE;
+    };
+    let written = match &options.output {
+        Some(path) => write(path, &generated.input)
+            .map_err(|e| format!("Failed writing {}: {e}", path.display())),
+        None => stdout()
+            .lock()
+            .write_all(generated.input.as_bytes())
+            .map_err(|e| e.to_string()),
+    };
+    if let Err(message) = written {
+        eprintln!("{message}");
+        return ExitCode::FAILURE;
+    }
+    for (part, answer) in [1, 2].into_iter().zip(&generated.answers) {
+        match answer {
+            Some(answer) => eprintln!("Part {part}: {answer}"),
+            None => eprintln!("Part {part}: unknown"),
+        }
+    }
+    ExitCode::SUCCESS
+}
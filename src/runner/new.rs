@@ -0,0 +1,18 @@
+use crate::scaffold::scaffold_day;
+use std::path::Path;
+use std::process::ExitCode;
+
+pub fn new_day(day: u8) -> ExitCode {
+    match scaffold_day(Path::new(env!("CARGO_MANIFEST_DIR")), day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("Wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
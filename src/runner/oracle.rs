@@ -0,0 +1,33 @@
+use crate::oracle::{oracles, OracleError};
+use crate::runner::DaySelection;
+use std::process::ExitCode;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct OracleOptions {
+    pub seeds: usize,
+    pub size: usize,
+}
+
+pub fn oracle(days: DaySelection, options: &OracleOptions) -> ExitCode {
+    let mut failed = false;
+    for oracle in oracles().iter().filter(|oracle| days.contains(oracle.day)) {
+        print!("Day {:02} {}: ", oracle.day, oracle.name);
+        match oracle.check(0..options.seeds as u64, options.size) {
+            Ok(()) => println!("agreed for {} seeds", options.seeds),
+            Err(OracleError::Disagreed(example)) => {
+                println!("disagreed");
+                println!("{example}");
+                failed = true;
+            }
+            Err(e) => {
+                println!("{e}");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
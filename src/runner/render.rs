@@ -0,0 +1,116 @@
+use crate::config::Config;
+use crate::day04::word_search::WordSearch;
+use crate::day08::antenna_map::AntennaMap;
+use crate::day12::garden_map::GardenMap;
+use crate::day14::robots::Robots;
+use crate::day15::robot_plan::RobotPlan;
+use crate::input::{input_dirs, read_day_input, InputSource};
+use crate::render::png::write_png;
+use crate::render::svg::write_svg;
+use crate::render::Snapshot;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct RenderOptions {
+    pub part: u8,
+    pub format: ImageFormat,
+    pub output: Option<PathBuf>,
+    pub cell_pixels: usize,
+    pub input: InputSource,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ImageFormat {
+    Svg,
+    Png,
+}
+
+impl ImageFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Svg => "svg",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+pub fn render(day: u8, options: &RenderOptions, config: &Config) -> ExitCode {
+    let input = match read_day_input(day, &options.input, &input_dirs(config)) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let path = options.output.clone().unwrap_or_else(|| {
+        let extension = options.format.extension();
+        PathBuf::from(format!("day{day:02}-part{}.{extension}", options.part))
+    });
+    match render_day(day, &input, options, &path) {
+        Ok(()) => {
+            println!("Rendered {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn render_day(day: u8, input: &str, options: &RenderOptions, path: &Path) -> Result<(), String> {
+    let parse_failed = |e| format!("Parse failed: {e}");
+    let snapshot: Box<dyn Snapshot> = match day {
+        4 => Box::new(WordSearch::parse(input).map_err(parse_failed)?),
+        8 => Box::new(AntennaMap::parse(input).map_err(parse_failed)?),
+        12 => Box::new(GardenMap::parse(input).map_err(parse_failed)?),
+        14 => Box::new(Robots::parse(input).map_err(parse_failed)?),
+        15 => Box::new(RobotPlan::parse(input).map_err(parse_failed)?),
+        _ => return Err(format!("No rendering for day {day}")),
+    };
+    let tiles = snapshot.snapshot(options.part);
+    let palette = snapshot.palette();
+    match options.format {
+        ImageFormat::Svg => write_svg(&tiles, &palette, options.cell_pixels, path),
+        ImageFormat::Png => write_png(&tiles, &palette, options.cell_pixels, path),
+    }
+    .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env::temp_dir;
+    use std::fs::{read_to_string, remove_file};
+
+    #[test]
+    fn can_render_garden_as_svg() {
+        let path = temp_dir().join(format!("aoc-render-day12-{}.svg", std::process::id()));
+        let options = RenderOptions {
+            part: 1,
+            format: ImageFormat::Svg,
+            output: None,
+            cell_pixels: 10,
+            input: InputSource::Search,
+        };
+        render_day(12, "AAAA\nBBCD\nBBCC\nEEEC\n", &options, &path).unwrap();
+        let svg = read_to_string(&path).unwrap();
+        remove_file(&path).unwrap();
+        assert!(svg.starts_with("<svg "));
+        assert_eq!(svg.matches("<rect ").count(), 1 + 16)
+    }
+
+    #[test]
+    fn can_refuse_day_without_rendering() {
+        let options = RenderOptions {
+            part: 1,
+            format: ImageFormat::Png,
+            output: None,
+            cell_pixels: 10,
+            input: InputSource::Search,
+        };
+        let path = temp_dir().join(format!("aoc-render-day01-{}.png", std::process::id()));
+        assert!(render_day(1, "", &options, &path).is_err())
+    }
+}
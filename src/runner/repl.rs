@@ -0,0 +1,35 @@
+use crate::config::Config;
+use crate::input::{input_dirs, read_day_input, InputSource};
+use crate::repl::{parse, Reply, Session};
+use std::io::{stdin, stdout, BufRead, Write};
+use std::process::ExitCode;
+
+pub fn repl(day: u8, input: &InputSource, config: &Config) -> ExitCode {
+    let parsed = read_day_input(day, input, &input_dirs(config))
+        .map_err(|e| e.to_string())
+        .and_then(|input| parse(day, &input));
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut session = Session::new(day, parsed.as_ref());
+    println!("Loaded day {day}, type help for commands");
+    let mut lines = stdin().lock().lines();
+    loop {
+        print!("{}", session.prompt());
+        let _ = stdout().flush();
+        let Some(Ok(line)) = lines.next() else {
+            println!();
+            return ExitCode::SUCCESS;
+        };
+        match session.execute(&line) {
+            Ok(Reply::Output(output)) if output.is_empty() => {}
+            Ok(Reply::Output(output)) => println!("{output}"),
+            Ok(Reply::Quit) => return ExitCode::SUCCESS,
+            Err(message) => println!("{message}"),
+        }
+    }
+}
@@ -0,0 +1,516 @@
+use crate::input::{hash_input, read_day_input, InputDir, InputSource};
+use crate::observe;
+use crate::observe::log::StructuredLog;
+use crate::observe::progress_bar::ProgressBar;
+use crate::observe::Observer;
+use crate::pool::Pool;
+use crate::runner::{DaySelection, PartSelection};
+use crate::sandbox::{run_limited, Exit, Limits};
+use crate::solution::{Answer, Parsed, Solver, DAYS};
+use crate::trace::{span, traced, write_chrome, Trace};
+use serde::{Deserialize, Serialize};
+use std::env::current_exe;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::process::{Command, ExitCode};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub answer_type: Option<String>,
+    pub duration_ms: f64,
+    pub input_hash: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct RunOptions {
+    pub part: PartSelection,
+    pub input: InputSource,
+    pub json: bool,
+    pub jobs: usize,
+    pub progress: Progress,
+    pub phases: bool,
+    pub trace: Option<PathBuf>,
+    pub limits: Limits,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Progress {
+    Silent,
+    Bar,
+    Log,
+}
+
+impl Progress {
+    fn install(&self) -> Result<(), String> {
+        let observer: Box<dyn Observer + Send> = match self {
+            Progress::Silent => return Ok(()),
+            Progress::Bar => Box::new(ProgressBar::stderr()),
+            Progress::Log => Box::new(StructuredLog::stderr()),
+        };
+        if observe::install(observer) {
+            Ok(())
+        } else {
+            Err("Progress is already being reported, ignoring --progress".to_string())
+        }
+    }
+}
+
+struct DayRun {
+    day: u8,
+    input_hash: Option<String>,
+    outcome: DayOutcome,
+    trace: Trace,
+}
+
+enum DayOutcome {
+    NoInput(String),
+    ParseFailed(Duration, Option<String>),
+    Parsed(Duration, Vec<PartRun>),
+    Reported(Vec<PartResult>),
+    TimedOut(Duration),
+    OutOfMemory(u64),
+    Crashed(String),
+}
+
+struct PartRun {
+    part: u8,
+    answer: Result<Answer, String>,
+    duration: Duration,
+}
+
+pub fn run(days: DaySelection, options: &RunOptions, dirs: &[InputDir]) -> ExitCode {
+    let RunOptions {
+        part, input, json, ..
+    } = options;
+    if let Err(e) = options.progress.install() {
+        eprintln!("{e}");
+    }
+    let solvers: Vec<&dyn Solver> = DAYS
+        .iter()
+        .filter(|solver| days.contains(solver.day()))
+        .copied()
+        .collect();
+    let [days_pool, day_pool] = share_jobs(options.jobs, solvers.len());
+    let day_runs = days_pool.map(&solvers, |solver| {
+        if options.limits.is_limited() {
+            run_day_limited(solver.day(), *part, input, dirs, options.limits, &day_pool)
+        } else {
+            run_day(*solver, *part, input, dirs, &day_pool)
+        }
+    });
+    let mut failed = false;
+    let mut results = vec![];
+    for day_run in &day_runs {
+        failed |= !day_run.succeeded();
+        if *json {
+            results.extend(day_run.results(*part));
+        } else {
+            day_run.print();
+        }
+        if options.phases {
+            day_run.print_phases(*json);
+        }
+    }
+    if solvers.is_empty() {
+        eprintln!("No solver registered for {days:?}");
+        return ExitCode::FAILURE;
+    }
+    if *json {
+        println!("{}", serde_json::to_string_pretty(&results).unwrap());
+    }
+    if let Some(path) = &options.trace {
+        let traces: Vec<&Trace> = day_runs.iter().map(|day_run| &day_run.trace).collect();
+        if let Err(e) = write_chrome(&traces, path) {
+            eprintln!("{e}");
+            failed = true;
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn share_jobs(jobs: usize, days: usize) -> [Pool; 2] {
+    let days_pool = Pool::new(jobs.min(days));
+    [days_pool, Pool::new(jobs / days_pool.jobs())]
+}
+
+fn run_day(
+    solver: &dyn Solver,
+    part: PartSelection,
+    source: &InputSource,
+    dirs: &[InputDir],
+    pool: &Pool,
+) -> DayRun {
+    let day = solver.day();
+    let ((input_hash, outcome), trace) =
+        traced(day, || solve_day(solver, part, source, dirs, pool));
+    DayRun {
+        day,
+        input_hash,
+        outcome,
+        trace,
+    }
+}
+
+fn run_day_limited(
+    day: u8,
+    part: PartSelection,
+    source: &InputSource,
+    dirs: &[InputDir],
+    limits: Limits,
+    pool: &Pool,
+) -> DayRun {
+    let trace = Trace { day, spans: vec![] };
+    let input = match read_day_input(day, source, dirs) {
+        Ok(input) => input,
+        Err(e) => {
+            return DayRun {
+                day,
+                input_hash: None,
+                outcome: DayOutcome::NoInput(e.to_string()),
+                trace,
+            }
+        }
+    };
+    let exit = current_exe()
+        .and_then(|exe| run_limited(&mut day_command(exe, day, part, pool), &input, limits));
+    let outcome = match exit {
+        Ok(Exit::Finished {
+            status,
+            stdout,
+            stderr,
+        }) => match serde_json::from_str(&stdout) {
+            Ok(results) => DayOutcome::Reported(results),
+            Err(_) => {
+                let reason = stderr
+                    .lines()
+                    .last()
+                    .map_or(status.to_string(), str::to_string);
+                DayOutcome::Crashed(reason)
+            }
+        },
+        Ok(Exit::TimedOut(timeout)) => DayOutcome::TimedOut(timeout),
+        Ok(Exit::OutOfMemory(memory_mb)) => DayOutcome::OutOfMemory(memory_mb),
+        Err(e) => DayOutcome::Crashed(format!("Failed starting child process: {e}")),
+    };
+    DayRun {
+        day,
+        input_hash: Some(hash_input(&input)),
+        outcome,
+        trace,
+    }
+}
+
+fn day_command(exe: PathBuf, day: u8, part: PartSelection, pool: &Pool) -> Command {
+    let mut command = Command::new(exe);
+    command.args(["run", &day.to_string(), "--json", "--input", "-"]);
+    command.args(["--jobs", &pool.jobs().to_string()]);
+    if let PartSelection::Part(part) = part {
+        command.args(["--part", &part.to_string()]);
+    }
+    command
+}
+
+fn solve_day(
+    solver: &dyn Solver,
+    part: PartSelection,
+    source: &InputSource,
+    dirs: &[InputDir],
+    pool: &Pool,
+) -> (Option<String>, DayOutcome) {
+    let input = match read_day_input(solver.day(), source, dirs) {
+        Ok(input) => input,
+        Err(e) => return (None, DayOutcome::NoInput(e.to_string())),
+    };
+    let input_hash = Some(hash_input(&input));
+    let (parsed, duration) = time(|| {
+        let _parse = span("parse");
+        solver.parse(&input)
+    });
+    let outcome = match parsed {
+        Some(Ok(parsed)) => {
+            let parts = [1, 2]
+                .into_iter()
+                .filter(|number| part.contains(*number))
+                .map(|number| {
+                    let (answer, duration) = time_part(parsed.as_ref(), number, pool);
+                    PartRun {
+                        part: number,
+                        answer,
+                        duration,
+                    }
+                })
+                .collect();
+            DayOutcome::Parsed(duration, parts)
+        }
+        Some(Err(e)) => DayOutcome::ParseFailed(duration, Some(e.to_string())),
+        None => DayOutcome::ParseFailed(duration, None),
+    };
+    (input_hash, outcome)
+}
+
+impl DayRun {
+    fn succeeded(&self) -> bool {
+        match &self.outcome {
+            DayOutcome::Parsed(_, parts) => parts.iter().all(|part| part.answer.is_ok()),
+            DayOutcome::Reported(results) => results.iter().all(|result| result.error.is_none()),
+            _ => false,
+        }
+    }
+
+    fn print(&self) {
+        println!("Day {:02}", self.day);
+        match &self.outcome {
+            DayOutcome::NoInput(message) => println!("  {}", message.replace('\n', "\n  ")),
+            DayOutcome::ParseFailed(duration, Some(e)) => {
+                println!("  Parse failed ({}): {e}", format_duration(*duration))
+            }
+            DayOutcome::ParseFailed(duration, None) => {
+                println!("  Parse failed ({})", format_duration(*duration))
+            }
+            DayOutcome::Parsed(duration, parts) => {
+                println!("  Parsed ({})", format_duration(*duration));
+                for PartRun {
+                    part,
+                    answer,
+                    duration,
+                } in parts
+                {
+                    match answer {
+                        Ok(answer) => {
+                            println!("  Part {part}: {answer} ({})", format_duration(*duration))
+                        }
+                        Err(error) => {
+                            println!("  Part {part}: {error} ({})", format_duration(*duration))
+                        }
+                    }
+                }
+            }
+            DayOutcome::Reported(results) => {
+                for result in results {
+                    let duration =
+                        format_duration(Duration::from_secs_f64(result.duration_ms / 1000.0));
+                    match (&result.answer, &result.error) {
+                        (Some(answer), None) => {
+                            println!("  Part {}: {answer} ({duration})", result.part)
+                        }
+                        (_, Some(error)) => {
+                            println!("  Part {}: {error} ({duration})", result.part)
+                        }
+                        (None, None) => println!("  Part {}: failed ({duration})", result.part),
+                    }
+                }
+            }
+            DayOutcome::TimedOut(timeout) => println!("  {}", timed_out(timeout)),
+            DayOutcome::OutOfMemory(memory_mb) => println!("  {}", out_of_memory(*memory_mb)),
+            DayOutcome::Crashed(reason) => println!("  Crashed: {reason}"),
+        }
+    }
+
+    fn print_phases(&self, to_stderr: bool) {
+        let phases = self.trace.phases();
+        if phases.is_empty() {
+            return;
+        }
+        let width = phases
+            .iter()
+            .map(|phase| phase.name.len() + phase.depth * 2)
+            .max()
+            .unwrap_or(0);
+        let mut lines = vec![format!("Day {:02} phases", self.day)];
+        for phase in phases {
+            let name = format!("{}{}", "  ".repeat(phase.depth), phase.name);
+            lines.push(format!(
+                "  {name:<width$} {:>6}x {}",
+                phase.count,
+                format_duration(phase.total)
+            ));
+        }
+        if to_stderr {
+            eprintln!("{}", lines.join("\n"));
+        } else {
+            println!("{}", lines.join("\n"));
+        }
+    }
+
+    fn results(&self, part: PartSelection) -> Vec<PartResult> {
+        let failed = |duration: &Duration, error: String| {
+            [1, 2]
+                .into_iter()
+                .filter(|number| part.contains(*number))
+                .map(|number| self.result(number, None, duration, Some(error.clone())))
+                .collect()
+        };
+        match &self.outcome {
+            DayOutcome::NoInput(message) => failed(&Duration::ZERO, message.clone()),
+            DayOutcome::ParseFailed(duration, e) => {
+                let error = e
+                    .as_ref()
+                    .map_or("Parse failed".to_string(), |e| format!("Parse failed: {e}"));
+                failed(duration, error)
+            }
+            DayOutcome::Parsed(_, parts) => parts
+                .iter()
+                .map(|run| match &run.answer {
+                    Ok(answer) => self.result(run.part, Some(answer.clone()), &run.duration, None),
+                    Err(error) => self.result(run.part, None, &run.duration, Some(error.clone())),
+                })
+                .collect(),
+            DayOutcome::Reported(results) => results.clone(),
+            DayOutcome::TimedOut(timeout) => failed(timeout, timed_out(timeout)),
+            DayOutcome::OutOfMemory(memory_mb) => {
+                failed(&Duration::ZERO, out_of_memory(*memory_mb))
+            }
+            DayOutcome::Crashed(reason) => failed(&Duration::ZERO, format!("Crashed: {reason}")),
+        }
+    }
+
+    fn result(
+        &self,
+        part: u8,
+        answer: Option<Answer>,
+        duration: &Duration,
+        error: Option<String>,
+    ) -> PartResult {
+        PartResult {
+            day: self.day,
+            part,
+            answer_type: answer.as_ref().map(|answer| match answer {
+                Answer::Number(_) => "number".to_string(),
+                Answer::Text(_) => "text".to_string(),
+            }),
+            answer,
+            duration_ms: duration.as_secs_f64() * 1000.0,
+            input_hash: self.input_hash.clone(),
+            error,
+        }
+    }
+}
+
+fn time_part(parsed: &dyn Parsed, part: u8, pool: &Pool) -> (Result<Answer, String>, Duration) {
+    let (answer, duration) = time(|| {
+        let _part = span(if part == 1 { "part 1" } else { "part 2" });
+        parsed.part_in_parallel(part, pool)
+    });
+    let answer = match answer {
+        Some(Some(answer)) => Ok(answer),
+        Some(None) => Err("No answer".to_string()),
+        None => Err("Failed".to_string()),
+    };
+    (answer, duration)
+}
+
+pub(super) fn time<T, F>(operation: F) -> (Option<T>, Duration)
+where
+    F: FnOnce() -> T,
+{
+    let start = Instant::now();
+    let result = catch_unwind(AssertUnwindSafe(operation)).ok();
+    (result, start.elapsed())
+}
+
+fn timed_out(timeout: &Duration) -> String {
+    format!("Timed out after {}s", timeout.as_secs_f64())
+}
+
+fn out_of_memory(memory_mb: u64) -> String {
+    format!("Out of memory (limit {memory_mb}MB)")
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::solver_for_day;
+
+    #[test]
+    fn can_format_duration() {
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.500ms")
+    }
+
+    #[test]
+    fn can_time_failing_operation() {
+        let (result, _): (Option<()>, Duration) = time(|| panic!("failed"));
+        assert_eq!(result, None)
+    }
+
+    #[test]
+    fn can_time_successful_operation() {
+        let (result, _) = time(|| 42);
+        assert_eq!(result, Some(42))
+    }
+
+    #[test]
+    fn can_share_jobs_between_days() {
+        let jobs = |jobs, days| share_jobs(jobs, days).map(|pool| pool.jobs());
+        assert_eq!(jobs(8, 1), [1, 8]);
+        assert_eq!(jobs(8, 15), [8, 1]);
+        assert_eq!(jobs(8, 3), [3, 2]);
+        assert_eq!(jobs(1, 0), [1, 1])
+    }
+
+    #[test]
+    fn can_pass_run_options_to_limited_day() {
+        let command = day_command(
+            PathBuf::from("aoc"),
+            7,
+            PartSelection::Part(2),
+            &Pool::new(4),
+        );
+        let args: Vec<_> = command
+            .get_args()
+            .map(|arg| arg.to_str().unwrap())
+            .collect();
+        assert_eq!(
+            args,
+            ["run", "7", "--json", "--input", "-", "--jobs", "4", "--part", "2"]
+        )
+    }
+
+    #[test]
+    fn can_report_answers_as_json() {
+        let solver = solver_for_day(1).unwrap();
+        let source = InputSource::Path(crate::input::file_in_src("day01/example.txt"));
+        let results = run_day(solver, PartSelection::Part(2), &source, &[], &Pool::new(1))
+            .results(PartSelection::Part(2));
+        let json = serde_json::to_value(&results).unwrap();
+        assert_eq!(json[0]["day"], 1);
+        assert_eq!(json[0]["part"], 2);
+        assert_eq!(json[0]["answer"], 31);
+        assert_eq!(json[0]["answer_type"], "number");
+        assert_eq!(json[0]["input_hash"].as_str().map(str::len), Some(16));
+        assert!(json[0]["error"].is_null())
+    }
+
+    #[test]
+    fn can_report_missing_input_as_json_error() {
+        let solver = solver_for_day(1).unwrap();
+        let results = run_day(
+            solver,
+            PartSelection::Both,
+            &InputSource::Search,
+            &[],
+            &Pool::new(1),
+        )
+        .results(PartSelection::Both);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].part, 2);
+        assert_eq!(results[1].answer, None);
+        assert_eq!(results[1].input_hash, None);
+        assert_eq!(
+            results[1].error.as_deref(),
+            Some("No input found for day 1, tried:")
+        )
+    }
+}
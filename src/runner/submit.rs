@@ -0,0 +1,76 @@
+use crate::client::guesses::GuessLog;
+use crate::client::submit::{submit_answer, Outcome};
+use crate::client::Client;
+use crate::config::Config;
+use crate::input::{input_dirs, read_day_input, InputSource};
+use crate::runner::run::time;
+use crate::solution::solver_for_day;
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub fn submit(day: u8, part: u8, source: &InputSource, config: &Config) -> ExitCode {
+    let Some(solver) = solver_for_day(day) else {
+        eprintln!("No solver registered for day {day}");
+        return ExitCode::FAILURE;
+    };
+    let dirs = input_dirs(config);
+    let input = match read_day_input(day, source, &dirs) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let parsed = match solver.parse(&input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("Parse failed: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(answer) = time(|| parsed.part(part)).0.flatten() else {
+        eprintln!("Part {part} failed");
+        return ExitCode::FAILURE;
+    };
+    let log_path = match &config.guess_log {
+        Some(path) => path.clone(),
+        None => dirs[0].path.join("guesses.tsv"),
+    };
+    let result = Client::from_config(config).and_then(|client| {
+        let mut log = GuessLog::open(&log_path)?;
+        submit_answer(&client, &mut log, [day, part], &answer.to_string(), now())
+    });
+    println!("Day {day:02} part {part}: submitting {answer}");
+    match result {
+        Ok(Outcome::Submitted(response)) => {
+            println!("  {}", response.verdict);
+            if let Some(wait) = response.wait {
+                println!("  Next guess allowed in {}s", wait.as_secs());
+            }
+            ExitCode::SUCCESS
+        }
+        Ok(Outcome::AlreadyGuessed(guess)) => {
+            println!("  Not sent, already guessed: {}", guess.verdict);
+            ExitCode::FAILURE
+        }
+        Ok(Outcome::AlreadySolved(guess)) => {
+            println!("  Not sent, already solved with {}", guess.answer);
+            ExitCode::FAILURE
+        }
+        Ok(Outcome::CoolingDown(wait)) => {
+            println!("  Not sent, next guess allowed in {}s", wait.as_secs());
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            println!("  {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
@@ -0,0 +1,293 @@
+use crate::answers::{Answers, AnswersError};
+use crate::config::Config;
+use crate::input::{file_in_src, input_dirs, read_day_input, InputDir, InputError, InputSource};
+use crate::runner::run::time;
+use crate::solution::{Answer, Solver, DAYS};
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Debug, Eq, PartialEq)]
+enum DayCheck {
+    MissingInput,
+    Failed(String),
+    Parts(Vec<PartCheck>),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum PartCheck {
+    Confirmed(Answer),
+    Mismatch { expected: Answer, actual: Answer },
+    New(Answer),
+    Failed,
+}
+
+#[derive(Debug, Default, Eq, PartialEq)]
+struct Summary {
+    confirmed: usize,
+    mismatched: usize,
+    new: usize,
+    failed: usize,
+    missing_inputs: usize,
+}
+
+pub fn verify(record: bool, config: &Config) -> ExitCode {
+    let dirs = input_dirs(config);
+    let Some(path) = answers_path(config, &dirs) else {
+        eprintln!(
+            "No answers file configured, set answers or input_dir in aoc.toml, or $AOC_INPUT_DIR"
+        );
+        return ExitCode::from(2);
+    };
+    let mut answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::from(2);
+        }
+    };
+    let mut summary = Summary::default();
+    for solver in DAYS {
+        let input = read_day_input(solver.day(), &InputSource::Search, &dirs);
+        let check = check_day(solver, input, &answers);
+        print_day(solver.day(), &check);
+        summary.add(&check);
+        if record {
+            if let Err(e) = record_new_answers(solver.day(), &check, &mut answers) {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    println!("{summary}");
+    if record && summary.new > 0 {
+        if let Err(e) = answers.save(&path) {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+        println!("Recorded {} new answers in {}", summary.new, path.display());
+    }
+    if summary.mismatched > 0 || summary.failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn answers_path(config: &Config, dirs: &[InputDir]) -> Option<PathBuf> {
+    let source_tree = file_in_src("");
+    let in_input_dir = || {
+        dirs.iter()
+            .find(|dir| dir.path != source_tree)
+            .map(|dir| dir.path.join("answers.toml"))
+    };
+    config.answers.clone().or_else(in_input_dir)
+}
+
+fn check_day(
+    solver: &dyn Solver,
+    input: Result<String, InputError>,
+    answers: &Answers,
+) -> DayCheck {
+    let input = match input {
+        Ok(input) => input,
+        Err(InputError::NotFound { .. }) => return DayCheck::MissingInput,
+        Err(e) => return DayCheck::Failed(e.to_string()),
+    };
+    let parsed = match time(|| solver.parse(&input)).0 {
+        Some(Ok(parsed)) => parsed,
+        Some(Err(e)) => return DayCheck::Failed(format!("Parse failed: {e}")),
+        None => return DayCheck::Failed("Parse failed".to_string()),
+    };
+    let parts = [1, 2]
+        .into_iter()
+        .map(|part| {
+            let actual = time(|| parsed.part(part)).0.flatten();
+            match (answers.get(solver.day(), part), actual) {
+                (_, None) => PartCheck::Failed,
+                (None, Some(actual)) => PartCheck::New(actual),
+                (Some(expected), Some(actual)) if *expected == actual => {
+                    PartCheck::Confirmed(actual)
+                }
+                (Some(expected), Some(actual)) => PartCheck::Mismatch {
+                    expected: expected.clone(),
+                    actual,
+                },
+            }
+        })
+        .collect();
+    DayCheck::Parts(parts)
+}
+
+fn print_day(day: u8, check: &DayCheck) {
+    match check {
+        DayCheck::MissingInput => println!("Day {day:02}: no input"),
+        DayCheck::Failed(message) => println!("Day {day:02}: {message}"),
+        DayCheck::Parts(parts) => {
+            println!("Day {day:02}");
+            for (part, check) in (1..).zip(parts) {
+                match check {
+                    PartCheck::Confirmed(answer) => println!("  Part {part}: ok ({answer})"),
+                    PartCheck::Mismatch { expected, actual } => {
+                        println!("  Part {part}: MISMATCH, expected {expected}, found {actual}")
+                    }
+                    PartCheck::New(answer) => println!("  Part {part}: new answer {answer}"),
+                    PartCheck::Failed => println!("  Part {part}: failed"),
+                }
+            }
+        }
+    }
+}
+
+fn record_new_answers(
+    day: u8,
+    check: &DayCheck,
+    answers: &mut Answers,
+) -> Result<(), AnswersError> {
+    if let DayCheck::Parts(parts) = check {
+        for (part, check) in (1..).zip(parts) {
+            if let PartCheck::New(answer) = check {
+                answers.set(day, part, answer.clone())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Summary {
+    fn add(&mut self, check: &DayCheck) {
+        match check {
+            DayCheck::MissingInput => self.missing_inputs += 1,
+            DayCheck::Failed(_) => self.failed += 1,
+            DayCheck::Parts(parts) => {
+                for part in parts {
+                    match part {
+                        PartCheck::Confirmed(_) => self.confirmed += 1,
+                        PartCheck::Mismatch { .. } => self.mismatched += 1,
+                        PartCheck::New(_) => self.new += 1,
+                        PartCheck::Failed => self.failed += 1,
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Confirmed {}, mismatched {}, new {}, failed {}, missing inputs {}",
+            self.confirmed, self.mismatched, self.new, self.failed, self.missing_inputs
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::input_to_string;
+    use crate::solution::solver_for_day;
+
+    #[test]
+    fn can_find_answers_in_first_input_dir() {
+        let dir = |path: &str, origin| InputDir {
+            path: PathBuf::from(path),
+            origin,
+        };
+        let source_tree = InputDir {
+            path: file_in_src(""),
+            origin: "source tree",
+        };
+        let dirs = [
+            dir("env", "AOC_INPUT_DIR"),
+            dir("inputs", "config input_dir"),
+            source_tree.clone(),
+        ];
+        assert_eq!(
+            answers_path(&Config::default(), &dirs),
+            Some(PathBuf::from("env/answers.toml"))
+        );
+        let config = Config {
+            answers: Some(PathBuf::from("answers/2024.toml")),
+            ..Config::default()
+        };
+        assert_eq!(
+            answers_path(&config, &dirs),
+            Some(PathBuf::from("answers/2024.toml"))
+        );
+        assert_eq!(answers_path(&Config::default(), &[source_tree]), None)
+    }
+
+    #[test]
+    fn can_confirm_and_find_new_answers() {
+        let answers = Answers::parse("[day07]\npart_1 = 3749").unwrap();
+        assert_eq!(
+            check_example(7, &answers),
+            DayCheck::Parts(vec![
+                PartCheck::Confirmed(Answer::Number(3749)),
+                PartCheck::New(Answer::Number(11387))
+            ])
+        )
+    }
+
+    #[test]
+    fn can_find_mismatch() {
+        let answers = Answers::parse("[day07]\npart_2 = 11386").unwrap();
+        assert_eq!(
+            check_example(7, &answers),
+            DayCheck::Parts(vec![
+                PartCheck::New(Answer::Number(3749)),
+                PartCheck::Mismatch {
+                    expected: Answer::Number(11386),
+                    actual: Answer::Number(11387)
+                }
+            ])
+        )
+    }
+
+    #[test]
+    fn can_report_missing_input() {
+        let solver = solver_for_day(7).unwrap();
+        let input = Err(InputError::NotFound {
+            day: 7,
+            tried: vec![],
+        });
+        assert_eq!(
+            check_day(solver, input, &Answers::default()),
+            DayCheck::MissingInput
+        )
+    }
+
+    #[test]
+    fn can_record_new_answers_only() {
+        let mut answers = Answers::parse("[day07]\npart_2 = 11386").unwrap();
+        let check = check_example(7, &answers);
+        record_new_answers(7, &check, &mut answers).unwrap();
+        assert_eq!(
+            [answers.get(7, 1), answers.get(7, 2)],
+            [Some(&Answer::Number(3749)), Some(&Answer::Number(11386))]
+        )
+    }
+
+    #[test]
+    fn can_summarise_checks() {
+        let mut summary = Summary::default();
+        summary.add(&DayCheck::MissingInput);
+        summary.add(&check_example(7, &Answers::default()));
+        assert_eq!(
+            summary.to_string(),
+            "Confirmed 0, mismatched 0, new 2, failed 0, missing inputs 1"
+        )
+    }
+
+    fn check_example(day: u8, answers: &Answers) -> DayCheck {
+        let solver = solver_for_day(day).unwrap();
+        let input = input_to_string(format!("day{day:02}/example.txt"));
+        check_day(
+            solver,
+            input.map_err(|e| InputError::Read("".into(), e)),
+            answers,
+        )
+    }
+}
@@ -0,0 +1,131 @@
+use crate::config::Config;
+use crate::day06::lab_room::LabRoom;
+use crate::day14::robots::Robots;
+use crate::day15::robot_plan::RobotPlan;
+use crate::input::{input_dirs, read_day_input, InputSource};
+use crate::visualise::ansi::play;
+use crate::visualise::gif::write_gif;
+use crate::visualise::text::write_text;
+use crate::visualise::{Frames, Visualise};
+use std::fs::File;
+use std::io::{stdout, BufWriter};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct VisualiseOptions {
+    pub format: FrameFormat,
+    pub output: Option<PathBuf>,
+    pub delay_ms: u64,
+    pub frames: Option<usize>,
+    pub input: InputSource,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum FrameFormat {
+    Text,
+    Ansi,
+    Gif,
+}
+
+pub fn visualise(day: u8, options: &VisualiseOptions, config: &Config) -> ExitCode {
+    let input = match read_day_input(day, &options.input, &input_dirs(config)) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match visualise_day(day, &input, options) {
+        Ok(count) => {
+            eprintln!("Wrote {count} frames");
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn visualise_day(day: u8, input: &str, options: &VisualiseOptions) -> Result<usize, String> {
+    let parse_failed = |e| format!("Parse failed: {e}");
+    match day {
+        6 => write_frames(&LabRoom::parse(input).map_err(parse_failed)?, day, options),
+        14 => write_frames(&Robots::parse(input).map_err(parse_failed)?, day, options),
+        15 => write_frames(
+            &RobotPlan::parse(input).map_err(parse_failed)?,
+            day,
+            options,
+        ),
+        _ => Err(format!("No visualisation for day {day}")),
+    }
+}
+
+fn write_frames(
+    visualise: &dyn Visualise,
+    day: u8,
+    options: &VisualiseOptions,
+) -> Result<usize, String> {
+    let frames: Frames = match options.frames {
+        Some(limit) => Box::new(visualise.frames().take(limit)),
+        None => visualise.frames(),
+    };
+    let delay = Duration::from_millis(options.delay_ms);
+    match (options.format, &options.output) {
+        (FrameFormat::Text, None) => {
+            write_text(frames, &mut stdout().lock()).map_err(|e| e.to_string())
+        }
+        (FrameFormat::Text, Some(path)) => File::create(path)
+            .and_then(|file| write_text(frames, &mut BufWriter::new(file)))
+            .map_err(|e| format!("Failed writing {}: {e}", path.display())),
+        (FrameFormat::Ansi, _) => {
+            play(frames, &mut stdout().lock(), delay).map_err(|e| e.to_string())
+        }
+        (FrameFormat::Gif, output) => {
+            let path = output
+                .clone()
+                .unwrap_or_else(|| PathBuf::from(format!("day{day:02}.gif")));
+            write_gif(frames, &path, delay).map_err(|e| e.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::input_to_string;
+    use std::env::temp_dir;
+    use std::fs::{read_to_string, remove_file};
+
+    #[test]
+    fn can_write_text_frames_to_file() {
+        let path = temp_dir().join(format!("aoc-visualise-day15-{}.txt", std::process::id()));
+        let options = VisualiseOptions {
+            format: FrameFormat::Text,
+            output: Some(path.clone()),
+            delay_ms: 0,
+            frames: Some(2),
+            input: InputSource::Search,
+        };
+        let input = input_to_string("day15/small-example.txt").unwrap();
+        assert_eq!(visualise_day(15, &input, &options), Ok(2));
+        let text = read_to_string(&path).unwrap();
+        remove_file(&path).unwrap();
+        assert!(text.starts_with("Start\n########\n"));
+        assert!(text.contains("Move 1: <\n"))
+    }
+
+    #[test]
+    fn can_refuse_day_without_visualisation() {
+        let options = VisualiseOptions {
+            format: FrameFormat::Text,
+            output: None,
+            delay_ms: 0,
+            frames: None,
+            input: InputSource::Search,
+        };
+        assert!(visualise_day(1, "", &options).is_err())
+    }
+}
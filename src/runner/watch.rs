@@ -0,0 +1,256 @@
+use crate::config::Config;
+use crate::input::{file_in_src, input_dirs, InputSource};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsString;
+use std::fs::{metadata, read_dir};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+use std::thread::sleep;
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct WatchOptions {
+    pub interval_ms: u64,
+    pub input: InputSource,
+}
+
+type Answers = BTreeMap<u8, Result<String, String>>;
+
+pub fn watch(day: u8, options: &WatchOptions, config: &Config) -> ExitCode {
+    let paths = watched_paths(day, &options.input, config);
+    println!("Watching day {day}:");
+    for path in &paths {
+        println!("  {}", path.display());
+    }
+    let mut previous = None;
+    loop {
+        let seen = modified_times(&paths);
+        test_examples(day);
+        if let Some(answers) = run_solver(day, &options.input) {
+            for line in describe_answers(previous.as_ref(), &answers) {
+                println!("{line}");
+            }
+            previous = Some(answers);
+        }
+        let changed = wait_for_change(&paths, &seen, Duration::from_millis(options.interval_ms));
+        println!();
+        for path in changed {
+            println!("Changed {}", path.display());
+        }
+    }
+}
+
+fn watched_paths(day: u8, input: &InputSource, config: &Config) -> Vec<PathBuf> {
+    let folder = file_in_src(format!("day{day:02}"));
+    let mut paths = vec![file_in_src(format!("day{day:02}.rs")), folder.clone()];
+    let inputs = match input {
+        InputSource::Path(path) => vec![path.clone()],
+        InputSource::Stdin => vec![],
+        InputSource::Search => input_dirs(config)
+            .iter()
+            .map(|dir| dir.day_input(day))
+            .collect(),
+    };
+    paths.extend(inputs.into_iter().filter(|path| {
+        let absolute = path.canonicalize().unwrap_or(path.clone());
+        !absolute.starts_with(&folder)
+    }));
+    paths
+}
+
+fn modified_times(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut times = BTreeMap::new();
+    for path in paths {
+        add_modified_times(path, &mut times);
+    }
+    times
+}
+
+fn add_modified_times(path: &Path, times: &mut BTreeMap<PathBuf, SystemTime>) {
+    let Ok(metadata) = metadata(path) else {
+        return;
+    };
+    if metadata.is_dir() {
+        for entry in read_dir(path).into_iter().flatten().flatten() {
+            add_modified_times(&entry.path(), times);
+        }
+    } else if let Ok(modified) = metadata.modified() {
+        times.insert(path.to_path_buf(), modified);
+    }
+}
+
+fn changed_paths(
+    before: &BTreeMap<PathBuf, SystemTime>,
+    after: &BTreeMap<PathBuf, SystemTime>,
+) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone())
+        .collect();
+    changed.extend(
+        before
+            .keys()
+            .filter(|path| !after.contains_key(*path))
+            .cloned(),
+    );
+    changed
+}
+
+fn wait_for_change(
+    paths: &[PathBuf],
+    seen: &BTreeMap<PathBuf, SystemTime>,
+    interval: Duration,
+) -> Vec<PathBuf> {
+    loop {
+        sleep(interval);
+        let changed = changed_paths(seen, &modified_times(paths));
+        if !changed.is_empty() {
+            return changed;
+        }
+    }
+}
+
+fn cargo(subcommand: &str) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or(OsString::from("cargo")));
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    command.arg(subcommand).arg("--manifest-path").arg(manifest);
+    command
+}
+
+fn test_examples(day: u8) {
+    let output = cargo("test")
+        .args(["--quiet", "--lib", &format!("day{day:02}::")])
+        .output();
+    match output {
+        Ok(output) if output.status.success() => println!("Tests passed"),
+        Ok(output) => {
+            println!("Tests failed");
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        }
+        Err(e) => eprintln!("Failed running cargo test: {e}"),
+    }
+}
+
+fn run_solver(day: u8, input: &InputSource) -> Option<Answers> {
+    let mut command = cargo("run");
+    command.args([
+        "--quiet",
+        "--bin",
+        "aoc",
+        "--",
+        "run",
+        &day.to_string(),
+        "--json",
+    ]);
+    if let InputSource::Path(path) = input {
+        command.arg("--input").arg(path);
+    }
+    let output = match command.output() {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed running cargo run: {e}");
+            return None;
+        }
+    };
+    let answers = parse_answers(&String::from_utf8_lossy(&output.stdout));
+    if answers.is_none() {
+        println!("Run failed");
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+    }
+    answers
+}
+
+fn parse_answers(json: &str) -> Option<Answers> {
+    let results: Vec<Value> = serde_json::from_str(json).ok()?;
+    results
+        .iter()
+        .map(|result| {
+            let part = result["part"].as_u64()? as u8;
+            let answer = match (&result["answer"], result["error"].as_str()) {
+                (_, Some(error)) => Err(error.to_string()),
+                (Value::String(text), None) => Ok(text.clone()),
+                (answer, None) => Ok(answer.to_string()),
+            };
+            Some((part, answer))
+        })
+        .collect()
+}
+
+fn describe_answers(previous: Option<&Answers>, answers: &Answers) -> Vec<String> {
+    answers
+        .iter()
+        .map(|(part, answer)| {
+            let before = previous.and_then(|previous| previous.get(part));
+            let answer = match answer {
+                Ok(answer) => answer.clone(),
+                Err(error) => format!("failed: {error}"),
+            };
+            match before {
+                None => format!("Part {part}: {answer}"),
+                Some(Ok(before)) if *before == answer => {
+                    format!("Part {part}: {answer} (unchanged)")
+                }
+                Some(Ok(before)) => format!("Part {part}: {answer} (was {before})"),
+                Some(Err(_)) => format!("Part {part}: {answer} (was failing)"),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env::temp_dir;
+    use std::fs::{create_dir_all, remove_dir_all, remove_file, write};
+
+    #[test]
+    fn can_parse_answers_from_run_json() {
+        let json = r#"[
+            {"day": 5, "part": 1, "answer": 143, "error": null},
+            {"day": 5, "part": 2, "answer": null, "error": "Failed"}
+        ]"#;
+        assert_eq!(
+            parse_answers(json),
+            Some(BTreeMap::from([
+                (1, Ok("143".to_string())),
+                (2, Err("Failed".to_string()))
+            ]))
+        );
+        assert_eq!(parse_answers("error: could not compile"), None)
+    }
+
+    #[test]
+    fn can_describe_answers_against_previous_run() {
+        let previous = BTreeMap::from([(1, Ok("143".to_string())), (2, Ok("120".to_string()))]);
+        let answers = BTreeMap::from([(1, Ok("143".to_string())), (2, Ok("123".to_string()))]);
+        assert_eq!(
+            describe_answers(Some(&previous), &answers),
+            ["Part 1: 143 (unchanged)", "Part 2: 123 (was 120)"]
+        );
+        assert_eq!(
+            describe_answers(None, &answers),
+            ["Part 1: 143", "Part 2: 123"]
+        )
+    }
+
+    #[test]
+    fn can_find_changed_files_in_watched_folder() {
+        let dir = temp_dir().join(format!("aoc-watch-changes-{}", std::process::id()));
+        create_dir_all(&dir).unwrap();
+        let watched = [dir.clone()];
+        let (kept, removed) = (dir.join("kept.rs"), dir.join("removed.rs"));
+        write(&kept, "").unwrap();
+        write(&removed, "").unwrap();
+        let before = modified_times(&watched);
+        remove_file(&removed).unwrap();
+        let added = dir.join("added.rs");
+        write(&added, "").unwrap();
+        let changed = changed_paths(&before, &modified_times(&watched));
+        remove_dir_all(&dir).unwrap();
+        assert_eq!(changed, [added, removed])
+    }
+}
@@ -0,0 +1,176 @@
+use std::io;
+use std::io::{Read, Write};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    pub memory_mb: Option<u64>,
+}
+
+#[derive(Debug)]
+pub enum Exit {
+    Finished {
+        status: ExitStatus,
+        stdout: String,
+        stderr: String,
+    },
+    TimedOut(Duration),
+    OutOfMemory(u64),
+}
+
+impl Limits {
+    pub fn is_limited(&self) -> bool {
+        self.timeout.is_some() || self.memory_mb.is_some()
+    }
+}
+
+pub fn run_limited(command: &mut Command, stdin: &str, limits: Limits) -> io::Result<Exit> {
+    command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(memory_mb) = limits.memory_mb {
+        cap_memory(command, memory_mb.saturating_mul(1024 * 1024));
+    }
+    let start = Instant::now();
+    let mut child = command.spawn()?;
+    let mut input = child.stdin.take().expect("stdin is piped");
+    let stdin = stdin.to_string();
+    let writer = thread::spawn(move || input.write_all(stdin.as_bytes()));
+    let stdout = read_in_background(child.stdout.take().expect("stdout is piped"));
+    let stderr = read_in_background(child.stderr.take().expect("stderr is piped"));
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        match limits.timeout {
+            Some(timeout) if start.elapsed() >= timeout => {
+                child.kill()?;
+                child.wait()?;
+                return Ok(Exit::TimedOut(timeout));
+            }
+            _ => sleep(POLL_INTERVAL),
+        }
+    };
+    let _ = writer.join();
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    match limits.memory_mb {
+        Some(memory_mb) if ran_out_of_memory(&stderr) => Ok(Exit::OutOfMemory(memory_mb)),
+        _ => Ok(Exit::Finished {
+            status,
+            stdout,
+            stderr,
+        }),
+    }
+}
+
+fn read_in_background<R: Read + Send + 'static>(mut reader: R) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut bytes = vec![];
+        let _ = reader.read_to_end(&mut bytes);
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+fn ran_out_of_memory(stderr: &str) -> bool {
+    stderr.contains("memory allocation of")
+}
+
+#[cfg(unix)]
+fn cap_memory(command: &mut Command, bytes: u64) {
+    use std::os::unix::process::CommandExt;
+
+    // A cap too large for rlim_t is beyond the address space anyway.
+    let bytes = libc::rlim_t::try_from(bytes).unwrap_or(libc::RLIM_INFINITY);
+    let limit = libc::rlimit {
+        rlim_cur: bytes,
+        rlim_max: bytes,
+    };
+    // Safety: setrlimit only changes the child's own limits and does not allocate.
+    unsafe {
+        command.pre_exec(move || match libc::setrlimit(libc::RLIMIT_AS, &limit) {
+            0 => Ok(()),
+            _ => Err(io::Error::last_os_error()),
+        });
+    }
+}
+
+#[cfg(not(unix))]
+fn cap_memory(_command: &mut Command, _bytes: u64) {}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn shell(script: &str) -> Command {
+        let mut command = Command::new("sh");
+        command.args(["-c", script]);
+        command
+    }
+
+    #[test]
+    fn can_pass_input_and_collect_output() {
+        let exit = run_limited(&mut shell("cat"), "1 2 3", Limits::default()).unwrap();
+        match exit {
+            Exit::Finished { status, stdout, .. } => {
+                assert!(status.success());
+                assert_eq!(stdout, "1 2 3")
+            }
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn can_stop_after_timeout() {
+        let limits = Limits {
+            timeout: Some(Duration::from_millis(50)),
+            memory_mb: None,
+        };
+        let start = Instant::now();
+        let exit = run_limited(&mut shell("sleep 5"), "", limits).unwrap();
+        assert!(matches!(exit, Exit::TimedOut(_)));
+        assert!(start.elapsed() < Duration::from_secs(5))
+    }
+
+    #[test]
+    fn can_cap_address_space() {
+        let limits = Limits {
+            timeout: None,
+            memory_mb: Some(256),
+        };
+        match run_limited(&mut shell("ulimit -v"), "", limits).unwrap() {
+            Exit::Finished { stdout, .. } => assert_eq!(stdout.trim(), "262144"),
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn can_report_failed_allocation_as_out_of_memory() {
+        let limits = Limits {
+            timeout: None,
+            memory_mb: Some(256),
+        };
+        let script = "echo 'memory allocation of 1073741824 bytes failed' >&2; kill -ABRT $$";
+        let exit = run_limited(&mut shell(script), "", limits).unwrap();
+        assert!(matches!(exit, Exit::OutOfMemory(256)))
+    }
+
+    #[test]
+    fn can_report_segfault_under_memory_cap_as_finished() {
+        let limits = Limits {
+            timeout: None,
+            memory_mb: Some(256),
+        };
+        match run_limited(&mut shell("kill -SEGV $$"), "", limits).unwrap() {
+            Exit::Finished { status, .. } => assert!(!status.success()),
+            other => panic!("{other:?}"),
+        }
+    }
+}
@@ -0,0 +1,261 @@
+pub mod example;
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::{create_dir_all, read_to_string, remove_dir, remove_file, write, OpenOptions};
+use std::io;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const DAY_TEMPLATE: &str = include_str!("scaffold/day.rs.template");
+const PUZZLE_TEMPLATE: &str = include_str!("scaffold/puzzle.rs.template");
+const LINE_WIDTH: usize = 100;
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Exists(PathBuf),
+    Registered(u8),
+    NoExample(PathBuf),
+    HasTests(PathBuf),
+    Read(PathBuf, io::Error),
+    Write(PathBuf, io::Error),
+}
+
+pub fn scaffold_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let src = root.join("src");
+    let lib_path = src.join("lib.rs");
+    let registry_path = src.join("solution/registry.rs");
+    let lib = read_to_string(&lib_path).map_err(|e| ScaffoldError::Read(lib_path.clone(), e))?;
+    let registry = read_to_string(&registry_path)
+        .map_err(|e| ScaffoldError::Read(registry_path.clone(), e))?;
+    let added = add_module(&lib, day).ok_or(ScaffoldError::Registered(day))?;
+    let registered = register_day(&registry, day).ok_or(ScaffoldError::Registered(day))?;
+    let files = [
+        (src.join(format!("day{day:02}.rs")), fill(DAY_TEMPLATE, day)),
+        (
+            src.join(format!("day{day:02}/puzzle.rs")),
+            fill(PUZZLE_TEMPLATE, day),
+        ),
+        (src.join(format!("day{day:02}/example.txt")), String::new()),
+    ];
+    if let Some((path, _)) = files.iter().find(|(path, _)| path.exists()) {
+        return Err(ScaffoldError::Exists(path.clone()));
+    }
+    let day_dir = src.join(format!("day{day:02}"));
+    create_dir_all(&day_dir).map_err(|e| ScaffoldError::Write(day_dir.clone(), e))?;
+    let mut created = vec![];
+    let result = files
+        .iter()
+        .try_for_each(|(path, contents)| {
+            write_new(path, contents)?;
+            created.push(path);
+            Ok(())
+        })
+        .and_then(|()| overwrite(&lib_path, &added))
+        .and_then(|()| overwrite(&registry_path, &registered));
+    if let Err(e) = result {
+        for path in created {
+            let _ = remove_file(path);
+        }
+        let _ = remove_dir(&day_dir);
+        let _ = write(&lib_path, lib);
+        return Err(e);
+    }
+    let mut written: Vec<PathBuf> = files.into_iter().map(|(path, _)| path).collect();
+    written.extend([lib_path, registry_path]);
+    Ok(written)
+}
+
+fn fill(template: &str, day: u8) -> String {
+    template
+        .replace("{{NN}}", &format!("{day:02}"))
+        .replace("{{N}}", &day.to_string())
+}
+
+fn write_new(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::AlreadyExists => ScaffoldError::Exists(path.to_path_buf()),
+            _ => ScaffoldError::Write(path.to_path_buf(), e),
+        })?;
+    file.write_all(contents.as_bytes())
+        .map_err(|e| ScaffoldError::Write(path.to_path_buf(), e))
+}
+
+fn overwrite(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    write(path, contents).map_err(|e| ScaffoldError::Write(path.to_path_buf(), e))
+}
+
+fn add_module(lib: &str, day: u8) -> Option<String> {
+    let module = format!("pub mod day{day:02};");
+    let mut lines: Vec<&str> = lib.lines().collect();
+    if lines.contains(&module.as_str()) {
+        return None;
+    }
+    let index = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod ") && *line > module.as_str())
+        .unwrap_or(lines.len());
+    lines.insert(index, &module);
+    Some(lines.join("\n") + "\n")
+}
+
+fn register_day(registry: &str, day: u8) -> Option<String> {
+    let is_day_import = |line: &&str| line.starts_with("use crate::day");
+    let mut days: Vec<u8> = registry
+        .lines()
+        .filter(is_day_import)
+        .filter_map(|line| line.get(14..16)?.parse().ok())
+        .collect();
+    if days.contains(&day) {
+        return None;
+    }
+    days.push(day);
+    days.sort();
+    let mut lines: Vec<String> = vec![];
+    let mut in_days = false;
+    for line in registry.lines() {
+        if is_day_import(&line) {
+            if !lines.iter().any(|line| line.starts_with("use crate::day")) {
+                lines.extend(
+                    days.iter()
+                        .map(|day| format!("use crate::day{day:02}::Day{day:02};")),
+                );
+            }
+        } else if line.starts_with("pub static DAYS") {
+            lines.extend(days_static(&days));
+            in_days = !line.ends_with("];");
+        } else if in_days {
+            in_days = !line.ends_with("];");
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+    Some(lines.join("\n") + "\n")
+}
+
+fn days_static(days: &[u8]) -> Vec<String> {
+    let opening = format!("pub static DAYS: [&dyn Solver; {}] = [", days.len());
+    let entries: Vec<String> = days.iter().map(|day| format!("&Day{day:02}")).collect();
+    let one_line = format!("{opening}{}];", entries.join(", "));
+    if one_line.len() <= LINE_WIDTH {
+        return vec![one_line];
+    }
+    let mut lines = vec![opening];
+    let mut line = String::from("   ");
+    for entry in entries {
+        if line.len() + entry.len() + 2 > LINE_WIDTH {
+            lines.push(line);
+            line = String::from("   ");
+        }
+        line.push_str(&format!(" {entry},"));
+    }
+    lines.push(line);
+    lines.push("];".to_string());
+    lines
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(f, "Refusing to overwrite {}", path.display()),
+            ScaffoldError::Registered(day) => write!(f, "Day {day} is already registered"),
+            ScaffoldError::NoExample(path) => write!(f, "No example found in {}", path.display()),
+            ScaffoldError::HasTests(path) => write!(f, "{} already has tests", path.display()),
+            ScaffoldError::Read(path, e) => write!(f, "Failed reading {}: {e}", path.display()),
+            ScaffoldError::Write(path, e) => write!(f, "Failed writing {}: {e}", path.display()),
+        }
+    }
+}
+
+impl Error for ScaffoldError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::file_in_src;
+    use std::env::temp_dir;
+    use std::fs::remove_dir_all;
+
+    #[test]
+    fn can_add_module_in_order() {
+        let lib = "pub mod day01;\npub mod day03;\npub mod grid;\n";
+        assert_eq!(
+            add_module(lib, 2),
+            Some("pub mod day01;\npub mod day02;\npub mod day03;\npub mod grid;\n".to_string())
+        );
+        assert_eq!(add_module(lib, 3), None)
+    }
+
+    #[test]
+    fn can_register_day_in_order() {
+        let registry = "\
+            use crate::day01::Day01;\n\
+            use crate::day03::Day03;\n\
+            use crate::solution::Solver;\n\
+            \n\
+            pub static DAYS: [&dyn Solver; 2] = [&Day01, &Day03];\n\
+            \n\
+            pub fn solver_for_day() {}\n";
+        assert_eq!(
+            register_day(registry, 2).unwrap(),
+            "\
+            use crate::day01::Day01;\n\
+            use crate::day02::Day02;\n\
+            use crate::day03::Day03;\n\
+            use crate::solution::Solver;\n\
+            \n\
+            pub static DAYS: [&dyn Solver; 3] = [&Day01, &Day02, &Day03];\n\
+            \n\
+            pub fn solver_for_day() {}\n"
+        );
+        assert_eq!(register_day(registry, 3), None)
+    }
+
+    #[test]
+    fn can_keep_registry_unchanged_when_re_registering_days() {
+        let registry = read_to_string(file_in_src("solution/registry.rs")).unwrap();
+        let without_day_15 = registry.replace("use crate::day15::Day15;\n", "");
+        let days = days_static(&(1..=14).collect::<Vec<u8>>()).join("\n");
+        let without_day_15 = without_day_15.replace(
+            &days_static(&(1..=15).collect::<Vec<u8>>()).join("\n"),
+            &days,
+        );
+        assert_eq!(register_day(&without_day_15, 15), Some(registry))
+    }
+
+    #[test]
+    fn can_scaffold_day_and_refuse_to_overwrite() {
+        let root = temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        create_dir_all(root.join("src/solution")).unwrap();
+        write(root.join("src/lib.rs"), "pub mod day15;\npub mod grid;\n").unwrap();
+        write(
+            root.join("src/solution/registry.rs"),
+            "use crate::day15::Day15;\n\npub static DAYS: [&dyn Solver; 1] = [&Day15];\n",
+        )
+        .unwrap();
+        let written = scaffold_day(&root, 16).unwrap();
+        assert_eq!(written.len(), 5);
+        let day = read_to_string(root.join("src/day16.rs")).unwrap();
+        assert!(day.contains("pub struct Day16;"));
+        assert!(read_to_string(root.join("src/lib.rs"))
+            .unwrap()
+            .contains("pub mod day15;\npub mod day16;\n"));
+        assert!(read_to_string(root.join("src/solution/registry.rs"))
+            .unwrap()
+            .contains("[&Day15, &Day16];"));
+        let registered = scaffold_day(&root, 16).unwrap_err();
+        write(root.join("src/day17.rs"), "").unwrap();
+        let exists = scaffold_day(&root, 17).unwrap_err();
+        let lib = read_to_string(root.join("src/lib.rs")).unwrap();
+        let puzzle_written = root.join("src/day17/puzzle.rs").exists();
+        remove_dir_all(&root).unwrap();
+        assert_eq!(registered.to_string(), "Day 16 is already registered");
+        assert!(matches!(exists, ScaffoldError::Exists(path) if path.ends_with("src/day17.rs")));
+        assert!(!lib.contains("day17"));
+        assert!(!puzzle_written)
+    }
+}
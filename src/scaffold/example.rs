@@ -0,0 +1,170 @@
+use crate::scaffold::ScaffoldError;
+use regex::Regex;
+use std::fs::{read_to_string, write};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct PuzzleExample {
+    pub input: String,
+    pub answers: Vec<String>,
+}
+
+impl PuzzleExample {
+    pub fn parse(html: &str) -> Option<PuzzleExample> {
+        let articles = articles(html);
+        let block = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+        let answer =
+            Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>").unwrap();
+        let input = articles
+            .iter()
+            .find_map(|article| block.captures(article))
+            .map(|captures| decode(&captures[1]))?;
+        let answers = articles
+            .iter()
+            .filter_map(|article| {
+                let captures = answer.captures_iter(article).last()?;
+                let text = captures.get(1).or(captures.get(2))?.as_str();
+                Some(decode(text))
+            })
+            .collect();
+        Some(PuzzleExample { input, answers })
+    }
+
+    fn tests(&self, day: u8) -> String {
+        let mut tests = String::from(
+            "\n#[cfg(test)]\n\
+             mod tests {\n    \
+                 use super::*;\n    \
+                 use crate::input::input_to_string;\n    \
+                 use crate::solution::{Answer, Solver};\n",
+        );
+        for (i, answer) in self.answers.iter().enumerate() {
+            let expected = match answer.parse::<i64>() {
+                Ok(number) => format!("Some(Answer::Number({number}))"),
+                Err(_) => format!("Some(Answer::Text({answer:?}.to_string()))"),
+            };
+            tests.push_str(&format!(
+                "\n    #[test]\n    \
+                 fn can_solve_part_{part}_for_example() {{\n        \
+                     let example = input_to_string(\"day{day:02}/example.txt\").unwrap();\n        \
+                     let parsed = Day{day:02}.parse(&example).unwrap();\n        \
+                     assert_eq!(parsed.part({part}), {expected})\n    \
+                 }}\n",
+                part = i + 1
+            ));
+        }
+        tests.push_str("}\n");
+        tests
+    }
+}
+
+pub fn extract_example(
+    root: &Path,
+    day: u8,
+    html_path: &Path,
+) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let html =
+        read_to_string(html_path).map_err(|e| ScaffoldError::Read(html_path.to_path_buf(), e))?;
+    let example =
+        PuzzleExample::parse(&html).ok_or(ScaffoldError::NoExample(html_path.to_path_buf()))?;
+    let src = root.join("src");
+    let day_path = src.join(format!("day{day:02}.rs"));
+    let example_path = src.join(format!("day{day:02}/example.txt"));
+    let day_source =
+        read_to_string(&day_path).map_err(|e| ScaffoldError::Read(day_path.clone(), e))?;
+    if day_source.contains("#[cfg(test)]") {
+        return Err(ScaffoldError::HasTests(day_path));
+    }
+    if read_to_string(&example_path).is_ok_and(|existing| !existing.is_empty()) {
+        return Err(ScaffoldError::Exists(example_path));
+    }
+    write(&example_path, &example.input)
+        .map_err(|e| ScaffoldError::Write(example_path.clone(), e))?;
+    write(&day_path, day_source + &example.tests(day))
+        .map_err(|e| ScaffoldError::Write(day_path.clone(), e))?;
+    Ok(vec![example_path, day_path])
+}
+
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let Some(length) = rest[start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[start..start + length]);
+        rest = &rest[start + length..];
+    }
+    articles
+}
+
+fn decode(html: &str) -> String {
+    let tag = Regex::new(r"<[^>]*>").unwrap();
+    tag.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env::temp_dir;
+    use std::fs::{create_dir_all, remove_dir_all};
+
+    const PAGE: &str = "<main>\n\
+        <article class=\"day-desc\"><h2>--- Day 1: Historian Hysteria ---</h2>\
+        <p>For example:</p>\n\
+        <pre><code>3   4\n4   3\n</code></pre>\n\
+        <p>The distance is <code>2 + 1</code>, a total of <code><em>11</em></code>!</p>\
+        </article>\n\
+        <p>Your puzzle answer was <code>1234</code>.</p>\
+        <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>\
+        <p>The similarity score is <em><code>31</code></em>.</p></article>\n\
+        </main>";
+
+    #[test]
+    fn can_extract_example_and_answers() {
+        assert_eq!(
+            PuzzleExample::parse(PAGE),
+            Some(PuzzleExample {
+                input: "3   4\n4   3\n".to_string(),
+                answers: vec!["11".to_string(), "31".to_string()]
+            })
+        )
+    }
+
+    #[test]
+    fn can_decode_highlighted_example() {
+        let page = "<article><pre><code>a &lt;<em>b</em>&gt; &amp;c\n</code></pre></article>";
+        let example = PuzzleExample::parse(page).unwrap();
+        assert_eq!(example.input, "a <b> &c\n");
+        assert!(example.answers.is_empty())
+    }
+
+    #[test]
+    fn can_find_no_example_outside_article() {
+        assert_eq!(PuzzleExample::parse("<pre><code>1\n</code></pre>"), None)
+    }
+
+    #[test]
+    fn can_write_example_and_failing_tests() {
+        let root = temp_dir().join(format!("aoc-example-{}", std::process::id()));
+        create_dir_all(root.join("src/day16")).unwrap();
+        write(root.join("src/day16.rs"), "pub struct Day16;\n").unwrap();
+        write(root.join("src/day16/example.txt"), "").unwrap();
+        let html_path = root.join("day16.html");
+        write(&html_path, PAGE).unwrap();
+        extract_example(&root, 16, &html_path).unwrap();
+        let example = read_to_string(root.join("src/day16/example.txt")).unwrap();
+        let source = read_to_string(root.join("src/day16.rs")).unwrap();
+        let error = extract_example(&root, 16, &html_path).unwrap_err();
+        remove_dir_all(&root).unwrap();
+        assert_eq!(example, "3   4\n4   3\n");
+        assert!(source.contains("fn can_solve_part_2_for_example() {"));
+        assert!(source.contains("assert_eq!(parsed.part(1), Some(Answer::Number(11)))"));
+        assert!(error.to_string().ends_with("day16.rs already has tests"))
+    }
+}
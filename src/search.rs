@@ -0,0 +1,249 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+pub struct SearchTree<N> {
+    order: Vec<N>,
+    parents: HashMap<N, N>,
+}
+
+pub struct Route<N> {
+    pub cost: usize,
+    pub path: Vec<N>,
+}
+
+impl<N: Copy + Eq + Hash> SearchTree<N> {
+    fn new(start: N) -> SearchTree<N> {
+        SearchTree {
+            order: vec![start],
+            parents: HashMap::new(),
+        }
+    }
+
+    fn visit(&mut self, node: N, parent: N) {
+        self.order.push(node);
+        self.parents.insert(node, parent);
+    }
+
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    pub fn contains(&self, node: N) -> bool {
+        self.order[0] == node || self.parents.contains_key(&node)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = N> + use<'_, N> {
+        self.order.iter().copied()
+    }
+
+    pub fn path_to(&self, node: N) -> Option<Vec<N>> {
+        if !self.contains(node) {
+            return None;
+        }
+        Some(reconstruct_path(&self.parents, node))
+    }
+}
+
+pub fn breadth_first<N, F, I>(start: N, mut neighbours: F) -> SearchTree<N>
+where
+    N: Copy + Eq + Hash,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut tree = SearchTree::new(start);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        for next in neighbours(node) {
+            if !tree.contains(next) {
+                tree.visit(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+    tree
+}
+
+pub fn depth_first<N, F, I>(start: N, mut neighbours: F) -> SearchTree<N>
+where
+    N: Copy + Eq + Hash,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut tree = SearchTree::new(start);
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        for next in neighbours(node) {
+            if !tree.contains(next) {
+                tree.visit(next, node);
+                stack.push(next);
+            }
+        }
+    }
+    tree
+}
+
+pub fn dijkstra<N, F, I, G>(start: N, neighbours: F, is_goal: G) -> Option<Route<N>>
+where
+    N: Copy + Eq + Hash + Ord,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+    G: FnMut(N) -> bool,
+{
+    a_star(start, neighbours, |_| 0, is_goal)
+}
+
+pub fn a_star<N, F, I, H, G>(
+    start: N,
+    mut neighbours: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<Route<N>>
+where
+    N: Copy + Eq + Hash + Ord,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+    H: FnMut(N) -> usize,
+    G: FnMut(N) -> bool,
+{
+    let mut costs = HashMap::from([(start, 0)]);
+    let mut parents = HashMap::new();
+    let mut done = HashSet::new();
+    let mut queue = BinaryHeap::from([Reverse((heuristic(start), start))]);
+    while let Some(Reverse((_, node))) = queue.pop() {
+        if !done.insert(node) {
+            continue;
+        }
+        let cost = costs[&node];
+        if is_goal(node) {
+            let path = reconstruct_path(&parents, node);
+            return Some(Route { cost, path });
+        }
+        for (next, step_cost) in neighbours(node) {
+            let next_cost = cost + step_cost;
+            if costs.get(&next).is_some_and(|known| *known <= next_cost) {
+                continue;
+            }
+            costs.insert(next, next_cost);
+            parents.insert(next, node);
+            queue.push(Reverse((next_cost + heuristic(next), next)));
+        }
+    }
+    None
+}
+
+pub fn count_paths<N, F, I, G>(start: N, mut neighbours: F, mut is_goal: G) -> usize
+where
+    N: Copy + Eq + Hash,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(N) -> bool,
+{
+    let mut counts = HashMap::new();
+    count_paths_from(start, &mut neighbours, &mut is_goal, &mut counts)
+}
+
+fn count_paths_from<N, F, I, G>(
+    node: N,
+    neighbours: &mut F,
+    is_goal: &mut G,
+    counts: &mut HashMap<N, usize>,
+) -> usize
+where
+    N: Copy + Eq + Hash,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(N) -> bool,
+{
+    if let Some(count) = counts.get(&node) {
+        return *count;
+    }
+    let count = if is_goal(node) {
+        1
+    } else {
+        let next: Vec<N> = neighbours(node).into_iter().collect();
+        next.into_iter()
+            .map(|next| count_paths_from(next, neighbours, is_goal, counts))
+            .sum()
+    };
+    counts.insert(node, count);
+    count
+}
+
+fn reconstruct_path<N: Copy + Eq + Hash>(parents: &HashMap<N, N>, end: N) -> Vec<N> {
+    let mut path = vec![end];
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(*parent);
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{Direction, Point};
+    use crate::grid::Grid;
+
+    #[test]
+    fn can_search_breadth_first() {
+        let tree = breadth_first(0, |n: u32| {
+            [n * 2 + 1, n * 2 + 2].into_iter().filter(|n| *n < 7)
+        });
+        assert_eq!(tree.iter().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(tree.path_to(5), Some(vec![0, 2, 5]));
+        assert_eq!(tree.path_to(7), None)
+    }
+
+    #[test]
+    fn can_search_depth_first() {
+        let tree = depth_first(0, |n: u32| {
+            [n * 2 + 1, n * 2 + 2].into_iter().filter(|n| *n < 7)
+        });
+        assert_eq!(tree.iter().collect::<Vec<_>>(), vec![0, 1, 2, 5, 6, 3, 4]);
+        assert_eq!(tree.path_to(6), Some(vec![0, 2, 6]))
+    }
+
+    #[test]
+    fn can_find_cheapest_route() {
+        let costs = Grid::parse(0, "1911\n1191\n9111").unwrap();
+        let weighted = |point| {
+            costs
+                .neighbours_4(point)
+                .map(|next| (next, costs[next].to_digit(10).unwrap() as usize))
+                .collect::<Vec<_>>()
+        };
+        let goal = Point::new(3, 0);
+        let route = dijkstra(Point::new(0, 0), weighted, |point| point == goal).unwrap();
+        assert_eq!(route.cost, 7);
+        assert_eq!(route.path.len(), 8);
+        let heuristic = |point: Point| goal.x.abs_diff(point.x) + goal.y.abs_diff(point.y);
+        let route = a_star(Point::new(0, 0), weighted, heuristic, |point| point == goal);
+        assert_eq!(route.map(|route| route.cost), Some(7))
+    }
+
+    #[test]
+    fn can_find_no_route() {
+        let route = dijkstra(0, |_: u32| [], |n| n == 1);
+        assert!(route.is_none())
+    }
+
+    #[test]
+    fn can_count_paths() {
+        let grid = &Grid::filled(3, 3, 0);
+        let right_or_down = |point| {
+            [Direction::Right, Direction::Down]
+                .into_iter()
+                .filter_map(move |direction| grid.step(point, direction))
+        };
+        let goal = Point::new(2, 2);
+        assert_eq!(
+            count_paths(Point::new(0, 0), right_or_down, |p| p == goal),
+            6
+        )
+    }
+}
@@ -0,0 +1,144 @@
+mod answer;
+mod registry;
+
+pub use answer::{Answer, IntoAnswer};
+pub use registry::{solver_for_day, DAYS};
+
+use crate::parse::ParseError;
+use crate::pool::Pool;
+use std::any::Any;
+use std::marker::PhantomData;
+
+pub trait Solution {
+    const DAY: u8;
+    type Input: 'static;
+    type Part1: IntoAnswer;
+    type Part2: IntoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_1(input: &Self::Input) -> Self::Part1;
+    fn part_2(input: &Self::Input) -> Self::Part2;
+
+    fn part_1_in_parallel(input: &Self::Input, _pool: &Pool) -> Self::Part1 {
+        Self::part_1(input)
+    }
+    fn part_2_in_parallel(input: &Self::Input, _pool: &Pool) -> Self::Part2 {
+        Self::part_2(input)
+    }
+}
+
+pub trait Solver: Sync {
+    fn day(&self) -> u8;
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError>;
+}
+
+pub trait Parsed {
+    fn part_1(&self) -> Option<Answer>;
+    fn part_2(&self) -> Option<Answer>;
+    fn part_in_parallel(&self, part: u8, pool: &Pool) -> Option<Answer>;
+    fn input(&self) -> &dyn Any;
+
+    fn part(&self, part: u8) -> Option<Answer> {
+        if part == 1 {
+            self.part_1()
+        } else {
+            self.part_2()
+        }
+    }
+}
+
+impl<S> Solver for S
+where
+    S: Solution + Sync + 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError> {
+        Ok(Box::new(ParsedInput::<S> {
+            input: S::parse(input)?,
+            solution: PhantomData,
+        }))
+    }
+}
+
+struct ParsedInput<S: Solution> {
+    input: S::Input,
+    solution: PhantomData<S>,
+}
+
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn part_1(&self) -> Option<Answer> {
+        S::part_1(&self.input).into_answer()
+    }
+    fn part_2(&self) -> Option<Answer> {
+        S::part_2(&self.input).into_answer()
+    }
+    fn part_in_parallel(&self, part: u8, pool: &Pool) -> Option<Answer> {
+        if part == 1 {
+            S::part_1_in_parallel(&self.input, pool).into_answer()
+        } else {
+            S::part_2_in_parallel(&self.input, pool).into_answer()
+        }
+    }
+    fn input(&self) -> &dyn Any {
+        &self.input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_number;
+
+    struct Doubling;
+
+    impl Solution for Doubling {
+        const DAY: u8 = 26;
+        type Input = usize;
+        type Part1 = usize;
+        type Part2 = String;
+
+        fn parse(input: &str) -> Result<usize, ParseError> {
+            parse_number(26, input, input)
+        }
+        fn part_1(input: &usize) -> usize {
+            input * 2
+        }
+        fn part_2(input: &usize) -> String {
+            format!("{input}{input}")
+        }
+    }
+
+    #[test]
+    fn can_solve_through_solver() {
+        let parsed = Doubling.parse("21").unwrap();
+        assert_eq!(
+            [parsed.part(1), parsed.part(2)],
+            [
+                Some(Answer::Number(42)),
+                Some(Answer::Text("2121".to_string()))
+            ]
+        )
+    }
+
+    #[test]
+    fn can_reach_parsed_input() {
+        let parsed = Doubling.parse("21").unwrap();
+        assert_eq!(parsed.input().downcast_ref::<usize>(), Some(&21))
+    }
+
+    #[test]
+    fn can_fail_parsing_through_solver() {
+        let error = Doubling.parse("2x").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "day 26, line 1, column 1: expected a number, found \"2x\""
+        )
+    }
+
+    #[test]
+    fn can_find_day_through_solver() {
+        assert_eq!(Doubling.day(), 26)
+    }
+}
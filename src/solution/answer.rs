@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+pub trait IntoAnswer {
+    fn into_answer(self) -> Option<Answer>;
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Answer {
+        Answer::Number(i64::from(value))
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Answer {
+        i64::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Number)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Answer {
+        Answer::Text(value)
+    }
+}
+
+impl IntoAnswer for i32 {
+    fn into_answer(self) -> Option<Answer> {
+        Some(self.into())
+    }
+}
+
+impl IntoAnswer for usize {
+    fn into_answer(self) -> Option<Answer> {
+        Some(self.into())
+    }
+}
+
+impl IntoAnswer for String {
+    fn into_answer(self) -> Option<Answer> {
+        Some(self.into())
+    }
+}
+
+impl<T: IntoAnswer> IntoAnswer for Option<T> {
+    fn into_answer(self) -> Option<Answer> {
+        self.and_then(IntoAnswer::into_answer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_print_number() {
+        assert_eq!(Answer::from(1234_usize).to_string(), "1234")
+    }
+
+    #[test]
+    fn can_print_negative_number() {
+        assert_eq!(Answer::from(-12).to_string(), "-12")
+    }
+
+    #[test]
+    fn can_print_text() {
+        assert_eq!(Answer::from("ABC".to_string()).to_string(), "ABC")
+    }
+
+    #[test]
+    fn can_print_number_too_large_for_i64() {
+        assert_eq!(Answer::from(usize::MAX).to_string(), usize::MAX.to_string())
+    }
+
+    #[test]
+    fn can_have_no_answer() {
+        assert_eq!(None::<usize>.into_answer(), None);
+        assert_eq!(Some(12_usize).into_answer(), Some(Answer::Number(12)))
+    }
+}
@@ -0,0 +1,59 @@
+use crate::day01::Day01;
+use crate::day02::Day02;
+use crate::day03::Day03;
+use crate::day04::Day04;
+use crate::day05::Day05;
+use crate::day06::Day06;
+use crate::day07::Day07;
+use crate::day08::Day08;
+use crate::day09::Day09;
+use crate::day10::Day10;
+use crate::day11::Day11;
+use crate::day12::Day12;
+use crate::day13::Day13;
+use crate::day14::Day14;
+use crate::day15::Day15;
+use crate::solution::Solver;
+
+pub static DAYS: [&dyn Solver; 15] = [
+    &Day01, &Day02, &Day03, &Day04, &Day05, &Day06, &Day07, &Day08, &Day09, &Day10, &Day11, &Day12,
+    &Day13, &Day14, &Day15,
+];
+
+pub fn solver_for_day(day: u8) -> Option<&'static dyn Solver> {
+    DAYS.iter().find(|solver| solver.day() == day).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::input_to_string;
+    use crate::solution::Answer;
+
+    #[test]
+    fn can_register_every_day_once_in_order() {
+        let days: Vec<u8> = DAYS.iter().map(|solver| solver.day()).collect();
+        assert!(days.iter().all(|day| (1..=25).contains(day)));
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]))
+    }
+
+    #[test]
+    fn can_find_solver_for_day() {
+        assert_eq!(solver_for_day(7).map(|solver| solver.day()), Some(7))
+    }
+
+    #[test]
+    fn can_find_no_solver_for_unsolved_day() {
+        assert!(solver_for_day(25).is_none())
+    }
+
+    #[test]
+    fn can_solve_example_through_registry() {
+        let example = input_to_string("day07/example.txt").unwrap();
+        let parsed = solver_for_day(7).unwrap().parse(&example).unwrap();
+        assert_eq!(
+            [parsed.part_1(), parsed.part_2()],
+            [Some(Answer::Number(3749)), Some(Answer::Number(11387))]
+        )
+    }
+}
@@ -0,0 +1,319 @@
+use serde::Serialize;
+use std::cell::{Cell, RefCell};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::write;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+static EPOCH: OnceLock<Instant> = OnceLock::new();
+static THREADS: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    static CURRENT: RefCell<Option<Context>> = const { RefCell::new(None) };
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+    static THREAD: u64 = THREADS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[derive(Debug, Clone)]
+pub struct Context {
+    collector: Arc<Mutex<Vec<SpanRecord>>>,
+    depth: usize,
+}
+
+#[derive(Debug)]
+pub struct Span {
+    open: Option<OpenSpan>,
+}
+
+#[derive(Debug)]
+struct OpenSpan {
+    collector: Arc<Mutex<Vec<SpanRecord>>>,
+    name: &'static str,
+    depth: usize,
+    start: Instant,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct SpanRecord {
+    pub name: &'static str,
+    pub depth: usize,
+    pub thread: u64,
+    pub start: Duration,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Trace {
+    pub day: u8,
+    pub spans: Vec<SpanRecord>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Phase {
+    pub name: &'static str,
+    pub depth: usize,
+    pub count: usize,
+    pub total: Duration,
+}
+
+#[derive(Debug)]
+pub enum TraceError {
+    Write(PathBuf, io::Error),
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChromeTrace<'a> {
+    trace_events: Vec<ChromeEvent<'a>>,
+    display_time_unit: &'static str,
+}
+
+#[derive(Serialize)]
+struct ChromeEvent<'a> {
+    name: &'a str,
+    cat: String,
+    ph: &'static str,
+    ts: f64,
+    dur: f64,
+    pid: u8,
+    tid: u64,
+}
+
+pub fn span(name: &'static str) -> Span {
+    let open = CURRENT.with_borrow(|current| {
+        current.as_ref().map(|context| {
+            let depth = DEPTH.replace(DEPTH.get() + 1);
+            OpenSpan {
+                collector: context.collector.clone(),
+                name,
+                depth,
+                start: Instant::now(),
+            }
+        })
+    });
+    Span { open }
+}
+
+pub fn traced<T, F>(day: u8, operation: F) -> (T, Trace)
+where
+    F: FnOnce() -> T,
+{
+    EPOCH.get_or_init(Instant::now);
+    let context = Context {
+        collector: Arc::default(),
+        depth: 0,
+    };
+    let collector = context.collector.clone();
+    let result = within(Some(context), operation);
+    let mut spans = std::mem::take(&mut *collector.lock().unwrap());
+    spans.sort_by_key(|span| span.start);
+    (result, Trace { day, spans })
+}
+
+pub fn context() -> Option<Context> {
+    CURRENT.with_borrow(|current| {
+        current.as_ref().map(|context| Context {
+            collector: context.collector.clone(),
+            depth: DEPTH.get(),
+        })
+    })
+}
+
+pub fn within<T, F>(context: Option<Context>, operation: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let depth = context.as_ref().map_or(0, |context| context.depth);
+    let _restore = Restore {
+        context: CURRENT.replace(context),
+        depth: DEPTH.replace(depth),
+    };
+    operation()
+}
+
+struct Restore {
+    context: Option<Context>,
+    depth: usize,
+}
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        CURRENT.set(self.context.take());
+        DEPTH.set(self.depth);
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let Some(open) = self.open.take() else {
+            return;
+        };
+        DEPTH.set(open.depth);
+        let epoch = *EPOCH.get_or_init(|| open.start);
+        open.collector.lock().unwrap().push(SpanRecord {
+            name: open.name,
+            depth: open.depth,
+            thread: THREAD.with(|thread| *thread),
+            start: open.start.saturating_duration_since(epoch),
+            duration: open.start.elapsed(),
+        });
+    }
+}
+
+impl Trace {
+    pub fn phases(&self) -> Vec<Phase> {
+        let mut paths: Vec<Vec<&'static str>> = vec![];
+        let mut phases: Vec<(Vec<&'static str>, Phase)> = vec![];
+        for (index, span) in self.spans.iter().enumerate() {
+            let parent = (0..index).rev().find(|before| {
+                let before = &self.spans[*before];
+                before.depth + 1 == span.depth
+                    && before.start + before.duration >= span.start + span.duration
+            });
+            let mut path = parent.map_or(vec![], |parent| paths[parent].clone());
+            path.push(span.name);
+            match phases
+                .iter_mut()
+                .find(|(phase_path, _)| *phase_path == path)
+            {
+                Some((_, phase)) => {
+                    phase.count += 1;
+                    phase.total += span.duration;
+                }
+                None => phases.push((
+                    path.clone(),
+                    Phase {
+                        name: span.name,
+                        depth: span.depth,
+                        count: 1,
+                        total: span.duration,
+                    },
+                )),
+            }
+            paths.push(path);
+        }
+        phases.into_iter().map(|(_, phase)| phase).collect()
+    }
+}
+
+pub fn chrome_json(traces: &[&Trace]) -> String {
+    let micros = |duration: Duration| duration.as_secs_f64() * 1_000_000.0;
+    let trace_events = traces
+        .iter()
+        .flat_map(|trace| {
+            trace.spans.iter().map(|span| ChromeEvent {
+                name: span.name,
+                cat: format!("day{:02}", trace.day),
+                ph: "X",
+                ts: micros(span.start),
+                dur: micros(span.duration),
+                pid: 1,
+                tid: span.thread,
+            })
+        })
+        .collect();
+    let trace = ChromeTrace {
+        trace_events,
+        display_time_unit: "ms",
+    };
+    serde_json::to_string(&trace).expect("traces are always valid JSON")
+}
+
+pub fn write_chrome(traces: &[&Trace], path: &Path) -> Result<(), TraceError> {
+    write(path, chrome_json(traces) + "\n").map_err(|e| TraceError::Write(path.to_path_buf(), e))
+}
+
+impl Display for TraceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TraceError::Write(path, e) => write!(f, "Failed writing {}: {e}", path.display()),
+        }
+    }
+}
+
+impl Error for TraceError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pool::Pool;
+
+    #[test]
+    fn can_record_nested_spans() {
+        let (answer, trace) = traced(12, || {
+            let _solve = span("solve");
+            for _ in 0..3 {
+                let _step = span("step");
+            }
+            42
+        });
+        assert_eq!(answer, 42);
+        assert_eq!(
+            trace
+                .phases()
+                .iter()
+                .map(|phase| (phase.name, phase.depth, phase.count))
+                .collect::<Vec<_>>(),
+            [("solve", 0, 1), ("step", 1, 3)]
+        )
+    }
+
+    #[test]
+    fn can_keep_phases_apart_under_different_parents() {
+        let (_, trace) = traced(12, || {
+            for part in ["part 1", "part 2"] {
+                let _part = span(part);
+                let _build = span("build");
+            }
+        });
+        assert_eq!(
+            trace
+                .phases()
+                .iter()
+                .map(|phase| (phase.name, phase.count))
+                .collect::<Vec<_>>(),
+            [("part 1", 1), ("build", 1), ("part 2", 1), ("build", 1)]
+        )
+    }
+
+    #[test]
+    fn can_ignore_spans_outside_trace() {
+        let _ignored = span("ignored");
+        let ((), trace) = traced(1, || {});
+        assert_eq!(trace.spans, [])
+    }
+
+    #[test]
+    fn can_record_spans_from_pool_workers() {
+        let (_, trace) = traced(6, || {
+            let _solve = span("solve");
+            Pool::new(4).map(&[1, 2, 3, 4], |_| {
+                let _line = span("line");
+            })
+        });
+        let phases = trace.phases();
+        assert_eq!(phases.len(), 2);
+        assert_eq!(
+            (phases[1].name, phases[1].depth, phases[1].count),
+            ("line", 1, 4)
+        )
+    }
+
+    #[test]
+    fn can_write_chrome_trace_events() {
+        let (_, trace) = traced(5, || {
+            let _parse = span("parse");
+        });
+        let json: serde_json::Value = serde_json::from_str(&chrome_json(&[&trace])).unwrap();
+        let event = &json["traceEvents"][0];
+        assert_eq!(
+            [&event["name"], &event["cat"], &event["ph"]],
+            ["parse", "day05", "X"]
+        );
+        assert!(event["dur"].as_f64().is_some())
+    }
+}
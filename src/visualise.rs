@@ -0,0 +1,65 @@
+pub mod ansi;
+pub mod gif;
+pub mod text;
+
+use crate::grid::Grid;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Frame {
+    pub caption: String,
+    pub tiles: Grid<char>,
+}
+
+pub type Frames<'a> = Box<dyn Iterator<Item = Frame> + 'a>;
+
+pub trait Visualise {
+    fn frames(&self) -> Frames<'_>;
+}
+
+#[derive(Debug)]
+pub enum VisualiseError {
+    NoFrames,
+    Write(PathBuf, io::Error),
+    Encode(PathBuf, String),
+    TooLarge([usize; 2]),
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, tiles: Grid<char>) -> Frame {
+        Frame {
+            caption: caption.into(),
+            tiles,
+        }
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.caption)?;
+        write!(f, "{}", self.tiles)
+    }
+}
+
+impl Display for VisualiseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VisualiseError::NoFrames => write!(f, "No frames to write"),
+            VisualiseError::Write(path, e) => write!(f, "Failed writing {}: {e}", path.display()),
+            VisualiseError::Encode(path, e) => {
+                write!(f, "Failed encoding {}: {e}", path.display())
+            }
+            VisualiseError::TooLarge([width, height]) => {
+                write!(
+                    f,
+                    "Frames of {width}x{height} pixels are too large for a GIF"
+                )
+            }
+        }
+    }
+}
+
+impl Error for VisualiseError {}
@@ -0,0 +1,46 @@
+use crate::visualise::Frame;
+use std::io;
+use std::io::Write;
+use std::thread::sleep;
+use std::time::Duration;
+
+const CLEAR_SCREEN: &str = "\x1b[2J";
+const CURSOR_HOME: &str = "\x1b[H";
+
+pub fn play<I, W>(frames: I, out: &mut W, delay: Duration) -> io::Result<usize>
+where
+    I: Iterator<Item = Frame>,
+    W: Write,
+{
+    let mut count = 0;
+    write!(out, "{CLEAR_SCREEN}")?;
+    for frame in frames {
+        if count > 0 {
+            sleep(delay);
+        }
+        write!(out, "{CURSOR_HOME}{frame}")?;
+        out.flush()?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    #[test]
+    fn can_redraw_each_frame_from_top_left() {
+        let frames = [
+            Frame::new("Step 0", Grid::parse(0, ".@").unwrap()),
+            Frame::new("Step 1", Grid::parse(0, "@.").unwrap()),
+        ];
+        let mut out = Vec::new();
+        play(frames.into_iter(), &mut out, Duration::ZERO).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[2J\x1b[HStep 0\n.@\n\x1b[HStep 1\n@.\n"
+        )
+    }
+}
@@ -0,0 +1,92 @@
+use crate::render::palette::Palette;
+use crate::visualise::{Frame, VisualiseError};
+use ::gif::{Encoder, Repeat};
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+use std::time::Duration;
+
+const TILE_PIXELS: usize = 4;
+
+pub fn write_gif<I>(frames: I, path: &Path, delay: Duration) -> Result<usize, VisualiseError>
+where
+    I: Iterator<Item = Frame>,
+{
+    let mut frames = frames.peekable();
+    let Some(first) = frames.peek() else {
+        return Err(VisualiseError::NoFrames);
+    };
+    let [width, height] = first.tiles.size().map(|length| length * TILE_PIXELS);
+    let (Ok(gif_width), Ok(gif_height)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(VisualiseError::TooLarge([width, height]));
+    };
+    let encode_error =
+        |e: ::gif::EncodingError| VisualiseError::Encode(path.to_path_buf(), e.to_string());
+    let file = File::create(path).map_err(|e| VisualiseError::Write(path.to_path_buf(), e))?;
+    let palette = Palette::default();
+    let colours: Vec<u8> = palette.colours().into_iter().flatten().collect();
+    let mut encoder = Encoder::new(BufWriter::new(file), gif_width, gif_height, &colours)
+        .map_err(encode_error)?;
+    encoder.set_repeat(Repeat::Infinite).map_err(encode_error)?;
+    let mut count = 0;
+    for frame in frames {
+        let image = ::gif::Frame {
+            width: gif_width,
+            height: gif_height,
+            delay: u16::try_from(delay.as_millis() / 10).unwrap_or(u16::MAX),
+            buffer: Cow::Owned(pixels(&frame, &palette, width, height)),
+            ..::gif::Frame::default()
+        };
+        encoder.write_frame(&image).map_err(encode_error)?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+fn pixels(frame: &Frame, palette: &Palette, width: usize, height: usize) -> Vec<u8> {
+    let mut pixels = vec![0; width * height];
+    for (point, tile) in frame.tiles.iter() {
+        let colour = palette.index(*tile) as u8;
+        for y in 0..TILE_PIXELS {
+            let row = (point.y * TILE_PIXELS + y) * width;
+            let start = row + point.x * TILE_PIXELS;
+            if start + TILE_PIXELS <= pixels.len() {
+                pixels[start..start + TILE_PIXELS].fill(colour);
+            }
+        }
+    }
+    pixels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    #[test]
+    fn can_scale_tiles_to_pixels() {
+        let frame = Frame::new("", Grid::parse(0, "#.").unwrap());
+        let pixels = pixels(&frame, &Palette::default(), 2 * TILE_PIXELS, TILE_PIXELS);
+        assert_eq!(&pixels[..2 * TILE_PIXELS], &[1, 1, 1, 1, 0, 0, 0, 0])
+    }
+
+    #[test]
+    fn can_refuse_to_write_no_frames() {
+        let path = std::env::temp_dir().join("aoc-no-frames.gif");
+        let result = write_gif(std::iter::empty(), &path, Duration::ZERO);
+        assert!(matches!(result, Err(VisualiseError::NoFrames)))
+    }
+
+    #[test]
+    fn can_refuse_frames_too_large_for_gif() {
+        let path = std::env::temp_dir().join(format!("aoc-too-large-{}.gif", std::process::id()));
+        let frame = Frame::new("", Grid::filled(20_000, 1, '.'));
+        let result = write_gif(std::iter::once(frame), &path, Duration::ZERO);
+        assert!(matches!(
+            result,
+            Err(VisualiseError::TooLarge([80_000, TILE_PIXELS]))
+        ));
+        assert!(!path.exists())
+    }
+}
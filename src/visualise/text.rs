@@ -0,0 +1,41 @@
+use crate::visualise::Frame;
+use std::io;
+use std::io::Write;
+
+pub fn write_text<I, W>(frames: I, out: &mut W) -> io::Result<usize>
+where
+    I: Iterator<Item = Frame>,
+    W: Write,
+{
+    let mut count = 0;
+    for frame in frames {
+        if count > 0 {
+            writeln!(out)?;
+        }
+        write!(out, "{frame}")?;
+        count += 1;
+    }
+    out.flush()?;
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    #[test]
+    fn can_write_frames_as_text() {
+        let frames = [
+            Frame::new("Step 0", Grid::parse(0, "#.\n.@").unwrap()),
+            Frame::new("Step 1", Grid::parse(0, "#@\n..").unwrap()),
+        ];
+        let mut out = Vec::new();
+        let count = write_text(frames.into_iter(), &mut out).unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Step 0\n#.\n.@\n\nStep 1\n#@\n..\n"
+        )
+    }
+}